@@ -3,7 +3,7 @@ use std::cell::RefCell;
 
 // A shared counter for demonstration
 thread_local! {
-    static TEST_COUNTER: RefCell<u32> = RefCell::new(0);
+    static TEST_COUNTER: RefCell<u32> = const { RefCell::new(0) };
 }
 
 // Module to contain the fixture functions with attribute-style macros
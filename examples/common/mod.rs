@@ -13,5 +13,5 @@ where
     // Restore the original panic hook
     panic::set_hook(old_hook);
 
-    return result;
+    result
 }
@@ -3,7 +3,7 @@ use std::cell::RefCell;
 
 // A shared counter for demonstration
 thread_local! {
-    static TEST_COUNTER: RefCell<u32> = RefCell::new(0);
+    static TEST_COUNTER: RefCell<u32> = const { RefCell::new(0) };
 }
 
 // Define a module for the first test example with the run_test method
@@ -84,8 +84,7 @@ fn increment_counter() {
 // Helper to get the counter value - used by both modules
 fn get_counter() -> u32 {
     // Reset counter after getting the value outside of test scope
-    let value = TEST_COUNTER.with(|counter| *counter.borrow());
-    return value;
+    TEST_COUNTER.with(|counter| *counter.borrow())
 }
 
 fn main() {
@@ -3,7 +3,7 @@ use std::cell::RefCell;
 
 // A shared counter for demonstration
 thread_local! {
-    static TEST_COUNTER: RefCell<u32> = RefCell::new(0);
+    static TEST_COUNTER: RefCell<u32> = const { RefCell::new(0) };
 }
 
 // Helper to increment the counter
@@ -15,7 +15,7 @@ fn increment_counter() {
 
 // Helper to get the counter value
 fn get_counter() -> u32 {
-    return TEST_COUNTER.with(|counter| *counter.borrow());
+    TEST_COUNTER.with(|counter| *counter.borrow())
 }
 
 // Define functions to demonstrate module fixtures
@@ -26,10 +26,10 @@ fn setup_module_fixtures() {
     println!("mod my_test_module {{");
     println!("    #[setup]");
     println!("    fn setup() {{ /* setup code */ }}");
-    println!("");
+    println!();
     println!("    #[tear_down]");
     println!("    fn tear_down() {{ /* cleanup code */ }}");
-    println!("");
+    println!();
     println!("    #[test]");
     println!("    fn test_something() {{ /* fixtures automatically applied */ }}");
     println!("}}\n");
@@ -11,7 +11,7 @@ static AFTER_ALL_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
 // A shared value for tests
 thread_local! {
-    static TEST_VALUE: RefCell<String> = RefCell::new(String::new());
+    static TEST_VALUE: RefCell<String> = const { RefCell::new(String::new()) };
 }
 
 // Helper to set the test value
@@ -8,7 +8,7 @@ use syn::{
 /// Registers a function to be run once before any test in the current module
 ///
 /// Example:
-/// ```
+/// ```no_run
 /// use rest::prelude::*;
 ///
 /// #[before_all]
@@ -43,7 +43,7 @@ pub fn before_all(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// Registers a function to be run once after all tests in the current module
 ///
 /// Example:
-/// ```
+/// ```no_run
 /// use rest::prelude::*;
 ///
 /// #[after_all]
@@ -78,7 +78,7 @@ pub fn after_all(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// Registers a function to be run before each test in the current module
 ///
 /// Example:
-/// ```
+/// ```no_run
 /// use rest::prelude::*;
 ///
 /// #[setup]
@@ -113,7 +113,7 @@ pub fn setup(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// Registers a function to be run after each test in the current module
 ///
 /// Example:
-/// ```
+/// ```no_run
 /// use rest::prelude::*;
 ///
 /// #[tear_down]
@@ -148,7 +148,7 @@ pub fn tear_down(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// Runs a function with setup and teardown fixtures from the current module
 ///
 /// Example:
-/// ```
+/// ```no_run
 /// use rest::prelude::*;
 ///
 /// #[with_fixtures]
@@ -189,6 +189,46 @@ pub fn with_fixtures(_attr: TokenStream, item: TokenStream) -> TokenStream {
     TokenStream::from(output)
 }
 
+/// Collects every failed assertion of a test and fails once at the end, listing them all
+///
+/// Example:
+/// ```no_run
+/// use rest::prelude::*;
+///
+/// struct Response {
+///     status: u16,
+///     body: String,
+/// }
+///
+/// # fn get(_path: &str) -> Response { Response { status: 200, body: "ok".to_string() } }
+/// #[test]
+/// #[soft]
+/// fn test_response() {
+///     let response = get("/health");
+///
+///     // Both failures are reported together
+///     expect!(response.status).to_equal(200);
+///     expect!(response.body).to_contain("ok");
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn soft(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input_fn = parse_macro_input!(item as ItemFn);
+    let fn_body = &input_fn.block;
+    let vis = &input_fn.vis; // Preserve visibility
+    let attrs = &input_fn.attrs; // Preserve attributes
+    let sig = &input_fn.sig; // Get function signature
+
+    let output = quote! {
+        #(#attrs)*
+        #vis #sig {
+            rest::backend::soft::soft_assertions(|| #fn_body)
+        }
+    };
+
+    TokenStream::from(output)
+}
+
 /// A struct to visit all functions in a module and add the with_fixtures attribute to test functions
 struct TestFunctionVisitor {}
 
@@ -217,18 +257,20 @@ impl VisitMut for TestFunctionVisitor {
 /// Runs all test functions in a module with setup and teardown fixtures
 ///
 /// Example:
-/// ```
+/// ```no_run
 /// use rest::prelude::*;
 ///
 /// #[with_fixtures_module]
 /// mod test_module {
+///     use super::*;
+///
 ///     #[setup]
-///     fn setup() {
+///     fn setup_function() {
 ///         // Initialize test environment
 ///     }
 ///     
 ///     #[tear_down]
-///     fn tear_down() {
+///     fn teardown_function() {
 ///         // Clean up test environment
 ///     }
 ///     
@@ -238,6 +280,7 @@ impl VisitMut for TestFunctionVisitor {
 ///         expect!(2 + 2).to_equal(4);
 ///     }
 /// }
+/// # fn main() {}
 /// ```
 #[proc_macro_attribute]
 pub fn with_fixtures_module(_attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -254,12 +297,12 @@ pub fn with_fixtures_module(_attr: TokenStream, item: TokenStream) -> TokenStrea
             }
 
             // Recursively process nested modules as well
-            if let Item::Mod(nested_mod) = item {
-                if let Some((_, nested_items)) = &mut nested_mod.content {
-                    for nested_item in nested_items.iter_mut() {
-                        if let Item::Fn(func) = nested_item {
-                            visitor.visit_item_fn_mut(func);
-                        }
+            if let Item::Mod(nested_mod) = item
+                && let Some((_, nested_items)) = &mut nested_mod.content
+            {
+                for nested_item in nested_items.iter_mut() {
+                    if let Item::Fn(func) = nested_item {
+                        visitor.visit_item_fn_mut(func);
                     }
                 }
             }
@@ -1,3 +1,6 @@
+use crate::backend::assertions::chain::ChainExpr;
+use crate::backend::assertions::comparison::Comparison;
+use crate::backend::assertions::location::SourceLocation;
 use crate::backend::assertions::sentence::AssertionSentence;
 use std::fmt::Debug;
 
@@ -19,6 +22,30 @@ pub struct AssertionStep {
     pub passed: bool,
     /// The logical operation connecting this step to the next one
     pub logical_op: Option<LogicalOp>,
+    /// Debug rendering of the value under test
+    pub actual: Option<String>,
+    /// Debug rendering of the quantity the matcher actually compared, when it differs from the value (e.g. its length)
+    pub received: Option<String>,
+    /// Structured expected and actual values, for comparison matchers whose failures render as a diff
+    pub comparison: Option<Comparison>,
+}
+
+impl AssertionStep {
+    /// Build the Jest-like "expected / received" lines describing this step
+    pub fn expected_received_lines(&self) -> Vec<String> {
+        let mut lines = vec![format!("expected: {}", self.sentence.format())];
+
+        match (&self.received, &self.actual) {
+            (Some(received), Some(actual)) => {
+                lines.push(format!("received: {}", received));
+                lines.push(format!("actual value: {}", actual));
+            }
+            (Some(received), None) | (None, Some(received)) => lines.push(format!("received: {}", received)),
+            (None, None) => {}
+        }
+
+        return lines;
+    }
 }
 
 /// Represents the complete assertion with all steps
@@ -28,14 +55,22 @@ pub struct Assertion<T> {
     pub value: T,
     /// The expression string (variable name)
     pub expr_str: &'static str,
+    /// Subject of the next steps when it differs from the expression, e.g. "user.name" after a projection
+    pub subject: Option<String>,
+    /// Description of the outer value when checking a value unwrapped from it, e.g. "be Some whose value"
+    pub unwrapped: Option<AssertionSentence>,
     /// Whether the current assertion is negated
     pub negated: bool,
     /// All steps in the assertion chain
     pub steps: Vec<AssertionStep>,
+    /// Expression tree of the steps, extended as the chain is written; `None` before the first step
+    pub chain: Option<ChainExpr>,
     /// Flag to track if this is part of a chain
     pub in_chain: bool,
     /// Flag to mark the final step in a chain
     pub is_final: bool,
+    /// Where the assertion was written, when created through the `expect!` macros
+    pub location: Option<SourceLocation>,
 }
 
 /// Represents the complete result of a test session
@@ -47,6 +82,8 @@ pub struct TestSessionResult {
     pub failed_count: usize,
     /// Detailed results of failed assertions
     pub failures: Vec<Assertion<()>>,
+    /// Snapshot entries no assertion checked, as `path › key`, or the ones removed in update mode
+    pub obsolete_snapshots: Vec<String>,
 }
 
 impl<T> Assertion<T> {
@@ -55,56 +92,250 @@ impl<T> Assertion<T> {
         return Self {
             value,
             expr_str,
+            subject: None,
+            unwrapped: None,
             negated: false,
             steps: Vec::new(),
+            chain: None,
             in_chain: false,
             is_final: true, // By default, single-step assertions are final
+            location: None,
         };
     }
 
     /// Add an assertion step and get back a cloned Assertion for chaining
-    pub fn add_step(&self, mut sentence: AssertionSentence, result: bool) -> Self
+    pub fn add_step(&self, sentence: AssertionSentence, result: bool) -> Self
+    where
+        T: Clone + Debug,
+    {
+        // Keep a rendering of the value for failure reports
+        let actual = Some(format!("{:?}", self.value));
+
+        return self.add_step_with_actual(sentence, result, actual);
+    }
+
+    /// Add an assertion step with an explicit rendering of the value, for values that cannot be rendered with `Debug`
+    pub(crate) fn add_step_with_actual(&self, mut sentence: AssertionSentence, result: bool, actual: Option<String>) -> Self
     where
         T: Clone,
     {
-        // Set the negation
-        sentence = sentence.with_negation(self.negated);
+        // Set the negation, combined with any negation the sentence already carries (e.g. from a `not` matcher)
+        let negated = sentence.negated != self.negated;
+        sentence = sentence.with_negation(negated);
 
-        // Clean and set the subject from the expression string
+        // Clean and set the subject from the expression string, or from the path of a projected value
         // Remove reference symbols like '&' for cleaner output
-        sentence.subject = self.expr_str.trim_start_matches('&').to_string();
+        sentence.subject = self.subject_name();
+
+        // Checks on an unwrapped value read as part of the outer value, e.g. "be Some whose value is greater than 3"
+        if let Some(outer) = &self.unwrapped {
+            let inner = sentence.format_with_conjugation("value");
+            sentence = AssertionSentence::new(outer.verb.clone(), format!("{} {}", outer.object, inner));
+            sentence.subject = self.subject_name();
+        }
 
         // Calculate the final pass/fail result with negation applied
         let passed = if self.negated { !result } else { result };
 
+        // Join the new step to the chain, by the operator written after the previous step
+        let new_chain = self.chain_with(ChainExpr::Step(self.steps.len()));
+
         // Create new steps by cloning the existing ones
         let mut new_steps = self.steps.clone();
 
         // Add the new step
-        new_steps.push(AssertionStep { sentence, passed, logical_op: None });
+        new_steps.push(AssertionStep { sentence, passed, logical_op: None, actual, received: None, comparison: None });
 
         return Self {
             value: self.value.clone(),
             expr_str: self.expr_str,
+            subject: self.subject.clone(),
+            unwrapped: self.unwrapped.clone(),
             negated: false, // Reset negation after using it
             steps: new_steps,
+            chain: Some(new_chain),
             in_chain: true, // Mark this as part of a chain
             is_final: true, // This step is final until a modifier makes it non-final
+            location: self.location,
+        };
+    }
+
+    /// The subject of the next steps: the projected path, or the expression without reference symbols
+    pub fn subject_name(&self) -> String {
+        return match &self.subject {
+            Some(subject) => subject.clone(),
+            None => self.expr_str.trim_start_matches('&').to_string(),
+        };
+    }
+
+    /// Continue the chain on a value unwrapped from this one, such as the content of `Some`
+    ///
+    /// `presence` is the check that the inner value exists, e.g. "be Some"; when it was just written and passed, the
+    /// checks on the inner value restate it, so it is dropped. `outer` introduces those checks, e.g. "be Some whose
+    /// value". Without an inner value the chain cannot continue, and there is no value of type `U` to build a failed
+    /// assertion from: the chain is reported here as failing, with `received` on the failed presence check, then this
+    /// panics with `missing`. Panicking is intended, as later matchers would have nothing to check; inside a soft
+    /// scope, the failure is recorded before the scope ends.
+    pub(crate) fn continue_with_inner<U>(
+        self,
+        inner: Option<U>,
+        presence: AssertionSentence,
+        outer: AssertionSentence,
+        received: Option<String>,
+        missing: String,
+    ) -> Assertion<U>
+    where
+        T: Clone + Debug,
+    {
+        let mut result = self;
+        result.mark_as_intermediate();
+
+        let Some(inner) = inner else {
+            // Report the chain with a failed presence check, unless it already fails
+            result.negated = false;
+            let mut failing = if result.calculate_chain_result() {
+                let failing = result.add_step(presence, false);
+                match received {
+                    Some(received) => failing.with_received(received),
+                    None => failing,
+                }
+            } else {
+                result.clone()
+            };
+            failing.mark_as_final();
+            drop(failing);
+
+            panic!("{}", missing);
+        };
+
+        let mut steps = result.steps.clone();
+        let mut chain = result.expression();
+        let restated = match (steps.last(), &chain) {
+            (Some(step), Some(expression)) => {
+                step.passed
+                    && !step.sentence.negated
+                    && step.sentence.format() == presence.format()
+                    && expression.last_step() == Some(steps.len() - 1)
+                    && step.logical_op != Some(LogicalOp::Or)
+            }
+            _ => false,
+        };
+        if restated {
+            steps.pop();
+            chain = chain.and_then(ChainExpr::without_last_step);
+        }
+
+        let unwrapped = match &result.unwrapped {
+            Some(previous) => {
+                AssertionSentence::new(previous.verb.clone(), format!("{} {}", previous.object, outer.format_with_conjugation("value")))
+            }
+            None => outer,
+        };
+
+        return Assertion {
+            value: inner,
+            expr_str: result.expr_str,
+            subject: result.subject.clone(),
+            unwrapped: Some(unwrapped),
+            negated: result.negated,
+            steps,
+            chain,
+            in_chain: true,
+            is_final: true,
+            location: result.location,
         };
     }
 
-    /// Set the logical operation for the last step
+    /// Record where the assertion was written
+    pub fn with_location(mut self, location: SourceLocation) -> Self {
+        self.location = Some(location);
+        return self;
+    }
+
+    /// Record the quantity the last step compared when it is derived from the value (e.g. its length)
+    pub fn with_received(mut self, received: impl Into<String>) -> Self {
+        if let Some(last) = self.steps.last_mut() {
+            last.received = Some(received.into());
+        }
+
+        return self;
+    }
+
+    /// Record the structured values the last step compared, so a failure can be rendered as a diff
+    pub fn with_comparison(mut self, comparison: Comparison) -> Self {
+        if let Some(last) = self.steps.last_mut() {
+            last.comparison = Some(comparison);
+        }
+
+        return self;
+    }
+
+    /// Set the logical operation joining the last step, or the group it ends, to what follows
     pub fn set_last_logic(&mut self, op: LogicalOp) {
         if let Some(last) = self.steps.last_mut() {
             last.logical_op = Some(op);
         }
     }
 
+    /// The chain with `operand` joined after it, by the operator set after the last step (AND when none was)
+    pub(crate) fn chain_with(&self, operand: ChainExpr) -> ChainExpr {
+        return match self.expression() {
+            Some(chain) => chain.join(self.steps.last().and_then(|step| step.logical_op).unwrap_or(LogicalOp::And), operand),
+            None => operand,
+        };
+    }
+
+    /// The expression tree the chain is evaluated with, or `None` when there are no steps
+    pub fn expression(&self) -> Option<ChainExpr> {
+        if self.chain.is_some() || self.steps.is_empty() {
+            return self.chain.clone();
+        }
+
+        // Steps added directly form a flat chain: OR-separated segments of AND-ed steps
+        let segments = self.group_steps_into_segments();
+        let alternatives: Vec<ChainExpr> = segments
+            .into_iter()
+            .map(|segment| {
+                let mut operands: Vec<ChainExpr> = segment.into_iter().map(ChainExpr::Step).collect();
+                return if operands.len() == 1 { operands.remove(0) } else { ChainExpr::All(operands) };
+            })
+            .collect();
+
+        return Some(ChainExpr::Any(alternatives));
+    }
+
     /// Mark this assertion as non-final (intermediate step in a chain)
     pub fn mark_as_intermediate(&mut self) {
         self.is_final = false;
     }
 
+    /// Continue the chain on a value computed from this one by consuming it, e.g. calling a `FnOnce` closure
+    pub(crate) fn map_value<U>(self, f: impl FnOnce(T) -> U) -> Assertion<U> {
+        // Dropping this assertion would evaluate it, so its fields are moved out of a never dropped copy instead
+        let mut this = std::mem::ManuallyDrop::new(self);
+        let subject = this.subject.take();
+        let unwrapped = this.unwrapped.take();
+        let steps = std::mem::take(&mut this.steps);
+        let chain = this.chain.take();
+
+        // SAFETY: `this` is never dropped and the value is read out of it only once; the fields it still owns are
+        // empty or `Copy`, so nothing leaks
+        let value = unsafe { std::ptr::read(&this.value) };
+
+        return Assertion {
+            value: f(value),
+            expr_str: this.expr_str,
+            subject,
+            unwrapped,
+            negated: this.negated,
+            steps,
+            chain,
+            in_chain: this.in_chain,
+            is_final: this.is_final,
+            location: this.location,
+        };
+    }
+
     /// Mark this assertion as final (last step in a chain)
     pub fn mark_as_final(&mut self) {
         self.is_final = true;
@@ -112,36 +343,12 @@ impl<T> Assertion<T> {
 
     /// Calculate if the entire chain passes
     pub fn calculate_chain_result(&self) -> bool {
-        if self.steps.is_empty() {
-            return true;
-        }
-
-        if self.steps.len() == 1 {
-            return self.steps[0].passed;
+        if let Some(chain) = &self.chain {
+            return chain.evaluate(&self.steps);
         }
 
-        if self.steps.len() == 2 {
-            let first = &self.steps[0];
-            let second = &self.steps[1];
-
-            match first.logical_op {
-                Some(LogicalOp::And) => return first.passed && second.passed,
-                Some(LogicalOp::Or) => return first.passed || second.passed,
-                None => return first.passed && second.passed, // Default to AND
-            }
-        }
-
-        // For multi-step chains, evaluate segments
-        let segments = self.group_steps_into_segments();
-        let segment_results = segments
-            .iter()
-            .map(|segment| {
-                return segment.iter().all(|&step_idx| self.steps[step_idx].passed);
-            })
-            .collect::<Vec<_>>();
-
-        // Combine segments with OR logic
-        return segment_results.iter().any(|&r| r);
+        // An empty chain has nothing that can fail
+        return self.expression().is_none_or(|expression| expression.evaluate(&self.steps));
     }
 
     /// Group steps into segments separated by OR operators
@@ -193,11 +400,17 @@ impl<T> Assertion<T> {
         // Get thread context information once
         let context = self.get_thread_context();
 
-        // Emit events when enhanced output is enabled
-        if context.use_enhanced_output {
+        // Emit events when enhanced output is enabled, or a report collects them
+        if context.use_enhanced_output || crate::config::is_event_system_enabled() {
             self.emit_assertion_events(passed, &context);
         }
 
+        // Inside a soft assertion scope, results are collected and failures reported when the scope ends
+        if crate::backend::soft::is_active() {
+            crate::backend::soft::record(self.to_type_erased(), passed);
+            return;
+        }
+
         // Handle failure cases with panic
         if !passed && !context.is_special_test {
             self.handle_assertion_failure(&context);
@@ -231,14 +444,7 @@ impl<T> Assertion<T> {
         let is_final = !self.steps.is_empty() && (self.steps.last().unwrap().logical_op.is_none() || self.steps.len() > 1);
 
         // Convert to a type-erased assertion for reporting
-        let type_erased = Assertion::<()> {
-            value: (),
-            expr_str: self.expr_str,
-            negated: self.negated,
-            steps: self.steps.clone(),
-            in_chain: self.in_chain,
-            is_final: self.is_final,
-        };
+        let type_erased = self.to_type_erased();
 
         // Emit appropriate events based on assertion result
         if passed && is_final {
@@ -250,6 +456,22 @@ impl<T> Assertion<T> {
         }
     }
 
+    /// Copy everything but the value, for reporting
+    fn to_type_erased(&self) -> Assertion<()> {
+        return Assertion::<()> {
+            value: (),
+            expr_str: self.expr_str,
+            subject: self.subject.clone(),
+            unwrapped: self.unwrapped.clone(),
+            negated: self.negated,
+            steps: self.steps.clone(),
+            chain: self.chain.clone(),
+            in_chain: self.in_chain,
+            is_final: self.is_final,
+            location: self.location,
+        };
+    }
+
     /// Handle assertion failures with appropriate panic messages
     fn handle_assertion_failure(&self, context: &ThreadContext) {
         // If there are no steps, use a simple default message
@@ -260,8 +482,30 @@ impl<T> Assertion<T> {
         // Get the first step for error message generation
         let step = &self.steps[0];
         let message = self.format_error_message(step, context);
+        let details = self.format_failed_steps_details();
+        let location = self.location.map(|location| format!("\n  at {}", location)).unwrap_or_default();
+
+        panic!("{}{}{}", message, details, location);
+    }
+
+    /// Format the "expected / received" lines of every failed step, one per line
+    fn format_failed_steps_details(&self) -> String {
+        let mut details = String::new();
+
+        for step in self.steps.iter().filter(|step| !step.passed) {
+            for line in step.expected_received_lines() {
+                details.push_str(&format!("\n  {}", line));
+            }
+
+            // The same diff as the console, without colors, so it is shown without the enhanced output too
+            if let Some(comparison) = &step.comparison {
+                for line in crate::frontend::render_diff(comparison, false) {
+                    details.push_str(&format!("\n  {}", line));
+                }
+            }
+        }
 
-        panic!("{}", message);
+        return details;
     }
 
     /// Format appropriate error message based on context
@@ -328,9 +572,8 @@ impl<T> Drop for Assertion<T> {
         });
 
         if should_evaluate {
-            // Check if automatic initialization is needed when enhanced output is enabled
-            let enhanced_output = crate::config::is_enhanced_output_enabled();
-            if enhanced_output {
+            // Check if automatic initialization is needed when enhanced output or a report is enabled
+            if crate::config::is_event_system_enabled() {
                 // Try to initialize the event system if not already initialized
                 crate::config::initialize();
             }
@@ -358,10 +601,10 @@ mod tests {
         let assertion = Assertion::new(42, "test_value");
         assert_eq!(assertion.value, 42);
         assert_eq!(assertion.expr_str, "test_value");
-        assert_eq!(assertion.negated, false);
+        assert!(!assertion.negated);
         assert_eq!(assertion.steps.len(), 0);
-        assert_eq!(assertion.in_chain, false);
-        assert_eq!(assertion.is_final, true);
+        assert!(!assertion.in_chain);
+        assert!(assertion.is_final);
     }
 
     #[test]
@@ -373,13 +616,13 @@ mod tests {
         // Check the new assertion
         assert_eq!(result.value, 42);
         assert_eq!(result.expr_str, "test_value");
-        assert_eq!(result.negated, false);
-        assert_eq!(result.in_chain, true);
-        assert_eq!(result.is_final, true);
+        assert!(!result.negated);
+        assert!(result.in_chain);
+        assert!(result.is_final);
 
         // Check the step
         assert_eq!(result.steps.len(), 1);
-        assert_eq!(result.steps[0].passed, true);
+        assert!(result.steps[0].passed);
         assert_eq!(result.steps[0].logical_op, None);
         assert_eq!(result.steps[0].sentence.subject, "test_value");
     }
@@ -399,20 +642,65 @@ mod tests {
             sentence,
             passed: false, // !true because of negation
             logical_op: None,
+            actual: None,
+            received: None,
+            comparison: None,
         };
 
         let result = Assertion {
             value: 42,
             expr_str: "test_value",
+            subject: None,
+            unwrapped: None,
             negated: false, // Reset negation
             steps: vec![step],
+            chain: None,
             in_chain: true,
             is_final: true,
+            location: None,
         };
 
         // Verify the expected behavior
-        assert_eq!(result.steps[0].passed, false);
-        assert_eq!(result.negated, false);
+        assert!(!result.steps[0].passed);
+        assert!(!result.negated);
+    }
+
+    #[test]
+    fn test_add_step_records_actual_value() {
+        let assertion = Assertion::new("hello", "greeting");
+        let result = assertion.add_step(AssertionSentence::new("have", "length 4"), false).with_received("length 5");
+
+        assert_eq!(result.steps[0].actual.as_deref(), Some("\"hello\""));
+        assert_eq!(result.steps[0].received.as_deref(), Some("length 5"));
+    }
+
+    #[test]
+    fn test_expected_received_lines() {
+        let step = AssertionStep {
+            sentence: AssertionSentence::new("be", "greater than 5").with_negation(true),
+            passed: false,
+            logical_op: None,
+            actual: Some("7".to_string()),
+            received: None,
+            comparison: None,
+        };
+
+        assert_eq!(step.expected_received_lines(), vec!["expected: not be greater than 5", "received: 7"]);
+
+        let derived = AssertionStep { received: Some("length 5".to_string()), ..step };
+
+        assert_eq!(derived.expected_received_lines(), vec!["expected: not be greater than 5", "received: length 5", "actual value: 7"]);
+    }
+
+    #[test]
+    fn test_format_failed_steps_details() {
+        let assertion = Assertion::new(3, "x");
+        let mut result = assertion.add_step(AssertionSentence::new("be", "positive"), true);
+        result.set_last_logic(LogicalOp::And);
+        let result = result.add_step(AssertionSentence::new("be", "greater than 5"), false);
+
+        // Only the failed step is detailed
+        assert_eq!(result.format_failed_steps_details(), "\n  expected: be greater than 5\n  received: 3");
     }
 
     #[test]
@@ -432,15 +720,29 @@ mod tests {
     fn test_calculate_chain_result_single_step() {
         // Create an assertion with a passing step
         let mut assertion_pass = Assertion::new(42, "test_value");
-        assertion_pass.steps.push(AssertionStep { sentence: AssertionSentence::new("be", "positive"), passed: true, logical_op: None });
+        assertion_pass.steps.push(AssertionStep {
+            sentence: AssertionSentence::new("be", "positive"),
+            passed: true,
+            logical_op: None,
+            actual: None,
+            received: None,
+            comparison: None,
+        });
 
-        assert_eq!(assertion_pass.calculate_chain_result(), true);
+        assert!(assertion_pass.calculate_chain_result());
 
         // Create an assertion with a failing step
         let mut assertion_fail = Assertion::new(42, "test_value");
-        assertion_fail.steps.push(AssertionStep { sentence: AssertionSentence::new("be", "negative"), passed: false, logical_op: None });
+        assertion_fail.steps.push(AssertionStep {
+            sentence: AssertionSentence::new("be", "negative"),
+            passed: false,
+            logical_op: None,
+            actual: None,
+            received: None,
+            comparison: None,
+        });
 
-        assert_eq!(assertion_fail.calculate_chain_result(), false);
+        assert!(!assertion_fail.calculate_chain_result());
     }
 
     #[test]
@@ -452,11 +754,21 @@ mod tests {
             sentence: AssertionSentence::new("be", "positive"),
             passed: true,
             logical_op: Some(LogicalOp::And),
+            actual: None,
+            received: None,
+            comparison: None,
         });
 
-        assertion_pass.steps.push(AssertionStep { sentence: AssertionSentence::new("be", "even"), passed: true, logical_op: None });
+        assertion_pass.steps.push(AssertionStep {
+            sentence: AssertionSentence::new("be", "even"),
+            passed: true,
+            logical_op: None,
+            actual: None,
+            received: None,
+            comparison: None,
+        });
 
-        assert_eq!(assertion_pass.calculate_chain_result(), true);
+        assert!(assertion_pass.calculate_chain_result());
 
         // Case 2: First step fails -> false
         let mut assertion_fail = Assertion::new(42, "test_value");
@@ -465,11 +777,21 @@ mod tests {
             sentence: AssertionSentence::new("be", "negative"),
             passed: false,
             logical_op: Some(LogicalOp::And),
+            actual: None,
+            received: None,
+            comparison: None,
         });
 
-        assertion_fail.steps.push(AssertionStep { sentence: AssertionSentence::new("be", "even"), passed: true, logical_op: None });
+        assertion_fail.steps.push(AssertionStep {
+            sentence: AssertionSentence::new("be", "even"),
+            passed: true,
+            logical_op: None,
+            actual: None,
+            received: None,
+            comparison: None,
+        });
 
-        assert_eq!(assertion_fail.calculate_chain_result(), false);
+        assert!(!assertion_fail.calculate_chain_result());
     }
 
     #[test]
@@ -481,11 +803,21 @@ mod tests {
             sentence: AssertionSentence::new("be", "negative"),
             passed: false,
             logical_op: Some(LogicalOp::Or),
+            actual: None,
+            received: None,
+            comparison: None,
         });
 
-        assertion_pass.steps.push(AssertionStep { sentence: AssertionSentence::new("be", "even"), passed: true, logical_op: None });
+        assertion_pass.steps.push(AssertionStep {
+            sentence: AssertionSentence::new("be", "even"),
+            passed: true,
+            logical_op: None,
+            actual: None,
+            received: None,
+            comparison: None,
+        });
 
-        assert_eq!(assertion_pass.calculate_chain_result(), true);
+        assert!(assertion_pass.calculate_chain_result());
 
         // Case 2: Both steps fail -> false
         let mut assertion_fail = Assertion::new(42, "test_value");
@@ -494,11 +826,21 @@ mod tests {
             sentence: AssertionSentence::new("be", "negative"),
             passed: false,
             logical_op: Some(LogicalOp::Or),
+            actual: None,
+            received: None,
+            comparison: None,
         });
 
-        assertion_fail.steps.push(AssertionStep { sentence: AssertionSentence::new("be", "odd"), passed: false, logical_op: None });
+        assertion_fail.steps.push(AssertionStep {
+            sentence: AssertionSentence::new("be", "odd"),
+            passed: false,
+            logical_op: None,
+            actual: None,
+            received: None,
+            comparison: None,
+        });
 
-        assert_eq!(assertion_fail.calculate_chain_result(), false);
+        assert!(!assertion_fail.calculate_chain_result());
     }
 
     #[test]
@@ -511,6 +853,9 @@ mod tests {
             sentence: AssertionSentence::new("be", "positive"),
             passed: true,
             logical_op: Some(LogicalOp::And),
+            actual: None,
+            received: None,
+            comparison: None,
         });
 
         // Step 2: value < 100 (true)
@@ -518,6 +863,9 @@ mod tests {
             sentence: AssertionSentence::new("be", "less than 100"),
             passed: true,
             logical_op: Some(LogicalOp::Or),
+            actual: None,
+            received: None,
+            comparison: None,
         });
 
         // Step 3: value < 0 (false)
@@ -525,10 +873,20 @@ mod tests {
             sentence: AssertionSentence::new("be", "negative"),
             passed: false,
             logical_op: Some(LogicalOp::And),
+            actual: None,
+            received: None,
+            comparison: None,
         });
 
         // Step 4: value = 0 (false)
-        assertion.steps.push(AssertionStep { sentence: AssertionSentence::new("be", "zero"), passed: false, logical_op: None });
+        assertion.steps.push(AssertionStep {
+            sentence: AssertionSentence::new("be", "zero"),
+            passed: false,
+            logical_op: None,
+            actual: None,
+            received: None,
+            comparison: None,
+        });
 
         // Should produce two segments:
         // 1. [0, 1] (positive AND less than 100) -> true
@@ -542,7 +900,7 @@ mod tests {
         assert_eq!(segments[1], vec![2, 3]);
 
         // Verify the chain result
-        assert_eq!(assertion.calculate_chain_result(), true);
+        assert!(assertion.calculate_chain_result());
     }
 
     #[test]
@@ -583,7 +941,7 @@ mod tests {
         sentence.subject = String::new(); // Simulate the vec case where subject doesn't contain "vec"
 
         let mut result = assertion;
-        result.steps.push(AssertionStep { sentence, passed: false, logical_op: None });
+        result.steps.push(AssertionStep { sentence, passed: false, logical_op: None, actual: None, received: None, comparison: None });
 
         let non_test_enhanced = ThreadContext { is_test: false, is_module_test: false, use_enhanced_output: true, is_special_test: false };
 
@@ -602,12 +960,18 @@ mod tests {
             sentence: AssertionSentence::new("be", "positive"),
             passed: true,
             logical_op: Some(LogicalOp::And),
+            actual: None,
+            received: None,
+            comparison: None,
         });
 
         assertion.steps.push(AssertionStep {
             sentence: AssertionSentence::new("be", "even"),
             passed: true,
             logical_op: Some(LogicalOp::Or),
+            actual: None,
+            received: None,
+            comparison: None,
         });
 
         // Second segment (false AND false) = false
@@ -615,12 +979,18 @@ mod tests {
             sentence: AssertionSentence::new("be", "negative"),
             passed: false,
             logical_op: Some(LogicalOp::And),
+            actual: None,
+            received: None,
+            comparison: None,
         });
 
         assertion.steps.push(AssertionStep {
             sentence: AssertionSentence::new("be", "odd"),
             passed: false,
             logical_op: Some(LogicalOp::Or),
+            actual: None,
+            received: None,
+            comparison: None,
         });
 
         // Third segment (true AND false) = false
@@ -628,9 +998,19 @@ mod tests {
             sentence: AssertionSentence::new("be", "greater than 0"),
             passed: true,
             logical_op: Some(LogicalOp::And),
+            actual: None,
+            received: None,
+            comparison: None,
         });
 
-        assertion.steps.push(AssertionStep { sentence: AssertionSentence::new("be", "less than 0"), passed: false, logical_op: None });
+        assertion.steps.push(AssertionStep {
+            sentence: AssertionSentence::new("be", "less than 0"),
+            passed: false,
+            logical_op: None,
+            actual: None,
+            received: None,
+            comparison: None,
+        });
 
         // Should have 3 segments with results: true, false, false
         // Overall chain result should be true (OR of all segments)
@@ -641,6 +1021,6 @@ mod tests {
         assert_eq!(segments[1], vec![2, 3]);
         assert_eq!(segments[2], vec![4, 5]);
 
-        assert_eq!(assertion.calculate_chain_result(), true);
+        assert!(assertion.calculate_chain_result());
     }
 }
@@ -0,0 +1,181 @@
+//! Expression tree of an assertion chain, built as steps and groups are written
+
+use crate::backend::assertions::{AssertionStep, LogicalOp};
+
+/// Expression tree of a chain, where AND binds tighter than OR and groups are kept explicit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChainExpr {
+    /// A single step, by index into the assertion's steps
+    Step(usize),
+    /// Every operand must pass
+    All(Vec<ChainExpr>),
+    /// At least one operand must pass
+    Any(Vec<ChainExpr>),
+    /// An explicitly parenthesised expression
+    Group(Box<ChainExpr>),
+    /// A negated group, written with `not()` before it
+    Not(Box<ChainExpr>),
+}
+
+impl ChainExpr {
+    /// Join `operand`, a step or a group, after this expression with `op`
+    ///
+    /// An AND operand joins the last alternative of an OR, since AND binds tighter.
+    pub fn join(self, op: LogicalOp, operand: ChainExpr) -> Self {
+        return match (self, op) {
+            (ChainExpr::Any(mut alternatives), LogicalOp::And) => {
+                let last = alternatives.pop().expect("an OR has operands");
+                alternatives.push(last.join(LogicalOp::And, operand));
+                ChainExpr::Any(alternatives)
+            }
+            (ChainExpr::Any(mut alternatives), LogicalOp::Or) => {
+                alternatives.push(operand);
+                ChainExpr::Any(alternatives)
+            }
+            (ChainExpr::All(mut operands), LogicalOp::And) => {
+                operands.push(operand);
+                ChainExpr::All(operands)
+            }
+            (expression, LogicalOp::And) => ChainExpr::All(vec![expression, operand]),
+            (expression, LogicalOp::Or) => ChainExpr::Any(vec![expression, operand]),
+        };
+    }
+
+    /// The step the expression ends with, or `None` when it ends with a group
+    pub(crate) fn last_step(&self) -> Option<usize> {
+        return match self {
+            ChainExpr::Step(index) => Some(*index),
+            ChainExpr::All(operands) | ChainExpr::Any(operands) => operands.last().and_then(ChainExpr::last_step),
+            ChainExpr::Group(_) | ChainExpr::Not(_) => None,
+        };
+    }
+
+    /// Remove the step the expression ends with, or get `None` when nothing is left
+    pub(crate) fn without_last_step(self) -> Option<Self> {
+        let (mut operands, node): (_, fn(Vec<ChainExpr>) -> ChainExpr) = match self {
+            ChainExpr::Step(_) => return None,
+            ChainExpr::All(operands) => (operands, ChainExpr::All),
+            ChainExpr::Any(operands) => (operands, ChainExpr::Any),
+            group @ (ChainExpr::Group(_) | ChainExpr::Not(_)) => return Some(group),
+        };
+
+        if let Some(rest) = operands.pop().and_then(ChainExpr::without_last_step) {
+            operands.push(rest);
+        }
+
+        return Some(Self::collapse(operands, node));
+    }
+
+    /// Shift the step indices of the expression, when its steps are appended after others
+    pub(crate) fn offset(self, by: usize) -> Self {
+        let shift = |operands: Vec<ChainExpr>| operands.into_iter().map(|operand| operand.offset(by)).collect();
+
+        return match self {
+            ChainExpr::Step(index) => ChainExpr::Step(index + by),
+            ChainExpr::All(operands) => ChainExpr::All(shift(operands)),
+            ChainExpr::Any(operands) => ChainExpr::Any(shift(operands)),
+            ChainExpr::Group(inner) => ChainExpr::Group(Box::new(inner.offset(by))),
+            ChainExpr::Not(inner) => ChainExpr::Not(Box::new(inner.offset(by))),
+        };
+    }
+
+    /// Whether the expression passes, given the results of the steps it refers to
+    pub fn evaluate(&self, steps: &[AssertionStep]) -> bool {
+        return match self {
+            ChainExpr::Step(index) => steps[*index].passed,
+            ChainExpr::All(operands) => operands.iter().all(|operand| operand.evaluate(steps)),
+            ChainExpr::Any(operands) => operands.iter().any(|operand| operand.evaluate(steps)),
+            ChainExpr::Group(inner) => inner.evaluate(steps),
+            ChainExpr::Not(inner) => !inner.evaluate(steps),
+        };
+    }
+
+    /// Render the expression with each step's verb conjugated for the subject, parenthesising explicit groups
+    ///
+    /// Steps about another subject than the first step, such as a projected field, are prefixed with their own subject.
+    pub fn render(&self, steps: &[AssertionStep], subject: &str) -> String {
+        let lead = steps.first().map(|step| step.sentence.subject.as_str()).unwrap_or_default();
+
+        return self.render_with_lead(steps, subject, lead);
+    }
+
+    fn render_with_lead(&self, steps: &[AssertionStep], subject: &str, lead: &str) -> String {
+        let join = |operands: &[ChainExpr], op: &str| {
+            return operands.iter().map(|operand| operand.render_with_lead(steps, subject, lead)).collect::<Vec<_>>().join(op);
+        };
+
+        return match self {
+            ChainExpr::Step(index) => {
+                let sentence = &steps[*index].sentence;
+                if sentence.subject == lead {
+                    sentence.format_with_conjugation(subject)
+                } else {
+                    format!("{} {}", sentence.subject, sentence.format_with_conjugation(&sentence.subject))
+                }
+            }
+            ChainExpr::All(operands) => join(operands, " AND "),
+            ChainExpr::Any(operands) => join(operands, " OR "),
+            ChainExpr::Group(inner) => format!("({})", inner.render_with_lead(steps, subject, lead)),
+            ChainExpr::Not(inner) => format!("not {}", inner.render_with_lead(steps, subject, lead)),
+        };
+    }
+
+    /// Avoid single-operand nodes, which would only add nesting
+    fn collapse(mut operands: Vec<ChainExpr>, node: fn(Vec<ChainExpr>) -> ChainExpr) -> ChainExpr {
+        if operands.len() == 1 {
+            return operands.remove(0);
+        }
+
+        return node(operands);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_binds_and_tighter_than_or() {
+        // a OR b AND c
+        let expression = ChainExpr::Step(0).join(LogicalOp::Or, ChainExpr::Step(1)).join(LogicalOp::And, ChainExpr::Step(2));
+
+        let expected = ChainExpr::Any(vec![ChainExpr::Step(0), ChainExpr::All(vec![ChainExpr::Step(1), ChainExpr::Step(2)])]);
+        assert_eq!(expression, expected);
+    }
+
+    #[test]
+    fn test_join_keeps_groups() {
+        // (a OR b) AND c
+        let group = ChainExpr::Group(Box::new(ChainExpr::Step(0).join(LogicalOp::Or, ChainExpr::Step(1))));
+        let expression = group.join(LogicalOp::And, ChainExpr::Step(2));
+
+        let expected = ChainExpr::All(vec![
+            ChainExpr::Group(Box::new(ChainExpr::Any(vec![ChainExpr::Step(0), ChainExpr::Step(1)]))),
+            ChainExpr::Step(2),
+        ]);
+        assert_eq!(expression, expected);
+    }
+
+    #[test]
+    fn test_without_last_step() {
+        // a OR b AND c, then a OR b, then a
+        let expression = ChainExpr::Step(0).join(LogicalOp::Or, ChainExpr::Step(1)).join(LogicalOp::And, ChainExpr::Step(2));
+        assert_eq!(expression.last_step(), Some(2));
+
+        let expression = expression.without_last_step().unwrap();
+        assert_eq!(expression, ChainExpr::Any(vec![ChainExpr::Step(0), ChainExpr::Step(1)]));
+
+        let expression = expression.without_last_step().unwrap();
+        assert_eq!(expression, ChainExpr::Step(0));
+        assert_eq!(expression.without_last_step(), None);
+    }
+
+    #[test]
+    fn test_offset() {
+        let group = ChainExpr::Group(Box::new(ChainExpr::Any(vec![ChainExpr::Step(0), ChainExpr::Step(1)])));
+
+        let shifted = group.offset(3);
+
+        assert_eq!(shifted, ChainExpr::Group(Box::new(ChainExpr::Any(vec![ChainExpr::Step(3), ChainExpr::Step(4)]))));
+    }
+}
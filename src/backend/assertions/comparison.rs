@@ -0,0 +1,180 @@
+//! Structured values captured by comparison matchers, so failures can be rendered as diffs
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+/// The expected and actual sides of a failed comparison, kept as text for type-erased reporting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Comparison {
+    /// Two strings, diffed line by line
+    Text { expected: String, actual: String },
+    /// Two sequences, each element rendered with `Debug` and diffed element by element
+    Sequence { expected: Vec<String>, actual: Vec<String> },
+    /// Two maps, each entry rendered as a `Debug` (key, value) pair and diffed key by key; JSON documents use the
+    /// paths of their differing values as keys
+    Map { expected: Vec<(String, String)>, actual: Vec<(String, String)> },
+    /// Any two values, pretty-printed with `{:#?}` and diffed line by line
+    Pretty { expected: String, actual: String },
+}
+
+impl Comparison {
+    /// Capture two values; strings are kept as text and anything else is pretty-printed
+    pub fn of_values<E, A>(expected: &E, actual: &A) -> Self
+    where
+        E: Debug + ?Sized,
+        A: Debug + ?Sized,
+    {
+        let expected = format!("{:#?}", expected);
+        let actual = format!("{:#?}", actual);
+
+        return match (unescape_debug_str(&expected), unescape_debug_str(&actual)) {
+            (Some(expected), Some(actual)) => Comparison::Text { expected, actual },
+            _ => Comparison::Pretty { expected, actual },
+        };
+    }
+
+    /// Capture two sequences element by element
+    pub fn of_sequences<E, A>(expected: impl IntoIterator<Item = E>, actual: impl IntoIterator<Item = A>) -> Self
+    where
+        E: Debug,
+        A: Debug,
+    {
+        let render = |item: &dyn Debug| format!("{:?}", item);
+        let expected = expected.into_iter().map(|item| render(&item)).collect();
+        let actual = actual.into_iter().map(|item| render(&item)).collect();
+
+        return Comparison::Sequence { expected, actual };
+    }
+
+    /// Capture two maps entry by entry
+    pub fn of_maps<K, V, L, W>(expected: impl IntoIterator<Item = (K, V)>, actual: impl IntoIterator<Item = (L, W)>) -> Self
+    where
+        K: Debug,
+        V: Debug,
+        L: Debug,
+        W: Debug,
+    {
+        let expected = expected.into_iter().map(|(key, value)| (format!("{:?}", key), format!("{:?}", value))).collect();
+        let actual = actual.into_iter().map(|(key, value)| (format!("{:?}", key), format!("{:?}", value))).collect();
+
+        return Comparison::Map { expected, actual };
+    }
+}
+
+/// Order two `Debug` renderings the way a reader expects, e.g. for map keys and JSON paths
+///
+/// Numbers come first, by value; other renderings follow, with runs of digits compared by value, so keys `2` and `10`
+/// and paths `$.items[2]` and `$.items[10]` are listed in that order.
+pub(crate) fn compare_rendered(a: &str, b: &str) -> Ordering {
+    let ordering = match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.total_cmp(&y),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => compare_natural(a, b),
+    };
+
+    // Renderings equal by value, like `1.0` and `1`, still need a stable order
+    return ordering.then_with(|| a.cmp(b));
+}
+
+/// Compare text with runs of ASCII digits compared by their value
+fn compare_natural(mut a: &str, mut b: &str) -> Ordering {
+    let digits = |text: &str| text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+
+    loop {
+        match (a.chars().next(), b.chars().next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let (a_end, b_end) = (digits(a), digits(b));
+                let a_number = a[..a_end].trim_start_matches('0');
+                let b_number = b[..b_end].trim_start_matches('0');
+
+                let ordering = a_number.len().cmp(&b_number.len()).then_with(|| a_number.cmp(b_number));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a = &a[a_end..];
+                b = &b[b_end..];
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                a = &a[x.len_utf8()..];
+                b = &b[y.len_utf8()..];
+            }
+        }
+    }
+}
+
+/// Turn the `Debug` rendering of a string back into the string itself, or `None` if it is not one
+fn unescape_debug_str(rendered: &str) -> Option<String> {
+    let inner = rendered.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                'n' => result.push('\n'),
+                'r' => result.push('\r'),
+                't' => result.push('\t'),
+                '0' => result.push('\0'),
+                '\\' => result.push('\\'),
+                '"' => result.push('"'),
+                '\'' => result.push('\''),
+                'u' => {
+                    let hex: String = chars.by_ref().skip(1).take_while(|&h| h != '}').collect();
+                    result.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                }
+                _ => return None,
+            },
+            // An unescaped quote means this was not a single string literal
+            '"' => return None,
+            other => result.push(other),
+        }
+    }
+
+    return Some(result);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_of_values_keeps_strings_as_text() {
+        let comparison = Comparison::of_values(&"line 1\nline \"2\"\t\u{1b}", &String::from("other"));
+
+        assert_eq!(comparison, Comparison::Text { expected: "line 1\nline \"2\"\t\u{1b}".to_string(), actual: "other".to_string() });
+    }
+
+    #[test]
+    fn test_of_values_pretty_prints_other_values() {
+        let comparison = Comparison::of_values(&Some(1), &None::<i32>);
+
+        assert_eq!(comparison, Comparison::Pretty { expected: "Some(\n    1,\n)".to_string(), actual: "None".to_string() });
+    }
+
+    #[test]
+    fn test_of_sequences_and_maps() {
+        let sequence = Comparison::of_sequences([1, 2], vec!["a"]);
+        assert_eq!(sequence, Comparison::Sequence { expected: vec!["1".into(), "2".into()], actual: vec!["\"a\"".into()] });
+
+        let map: BTreeMap<&str, i32> = [("a", 1)].into_iter().collect();
+        let comparison = Comparison::of_maps(map.iter(), Vec::<(i32, i32)>::new());
+        assert_eq!(comparison, Comparison::Map { expected: vec![("\"a\"".into(), "1".into())], actual: vec![] });
+    }
+
+    #[test]
+    fn test_compare_rendered_orders_numbers_by_value() {
+        let mut rendered = vec!["10", "-1.5", "2", "\"b\"", "$.items[10].id", "$.items[2].name", "$.items[2].id", "\"a\""];
+        rendered.sort_by(|a, b| compare_rendered(a, b));
+
+        assert_eq!(rendered, vec!["-1.5", "2", "10", "\"a\"", "\"b\"", "$.items[2].id", "$.items[2].name", "$.items[10].id"]);
+        assert_eq!(compare_rendered("k07", "k7"), Ordering::Less);
+    }
+}
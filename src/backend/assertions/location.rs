@@ -0,0 +1,101 @@
+//! Source locations of assertions, captured by the `expect!` macros
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Where an assertion was written, as reported by `file!()`, `line!()` and `column!()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// Path of the source file, relative to the workspace root
+    pub file: &'static str,
+    /// 1-based line number
+    pub line: u32,
+    /// 1-based column number
+    pub column: u32,
+}
+
+impl SourceLocation {
+    /// Creates a new source location
+    pub const fn new(file: &'static str, line: u32, column: u32) -> Self {
+        return Self { file, line, column };
+    }
+
+    /// Read the surrounding source lines from disk, marking the assertion line and column
+    ///
+    /// Returns `None` when the source file cannot be found, e.g. when tests run away from the sources.
+    pub fn code_frame(&self, context: usize) -> Option<Vec<String>> {
+        let source = std::fs::read_to_string(self.resolve_path()?).ok()?;
+        let lines: Vec<&str> = source.lines().collect();
+
+        let target = (self.line as usize).checked_sub(1)?;
+        if target >= lines.len() {
+            return None;
+        }
+
+        let first = target.saturating_sub(context);
+        let last = (target + context).min(lines.len() - 1);
+        let width = (last + 1).to_string().len();
+
+        let mut frame = Vec::new();
+        for (index, line) in lines.iter().enumerate().take(last + 1).skip(first) {
+            let marker = if index == target { '>' } else { ' ' };
+            frame.push(format!("{} {:>width$} | {}", marker, index + 1, line, width = width).trim_end().to_string());
+
+            if index == target {
+                let padding = " ".repeat((self.column as usize).saturating_sub(1));
+                frame.push(format!("  {:>width$} | {}^", "", padding, width = width));
+            }
+        }
+
+        return Some(frame);
+    }
+
+    /// Find the source file, which `file!()` gives relative to the workspace root rather than the current directory
+    pub(crate) fn resolve_path(&self) -> Option<PathBuf> {
+        let path = Path::new(self.file);
+        if path.is_file() {
+            return Some(path.to_path_buf());
+        }
+
+        let manifest_dir = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR")?);
+        return manifest_dir.ancestors().map(|dir| dir.join(path)).find(|candidate| candidate.is_file());
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let location = SourceLocation::new("src/lib.rs", 12, 5);
+
+        assert_eq!(location.to_string(), "src/lib.rs:12:5");
+    }
+
+    #[test]
+    fn test_code_frame_marks_line_and_column() {
+        let line = line!() + 1;
+        let location = SourceLocation::new(file!(), line, 24);
+
+        let frame = location.code_frame(1).unwrap();
+
+        assert_eq!(frame.len(), 4);
+        assert!(frame[1].starts_with(&format!("> {} |", line)));
+        assert!(frame[1].ends_with("let location = SourceLocation::new(file!(), line, 24);"));
+        assert_eq!(frame[2].find('^').unwrap() - frame[2].find('|').unwrap(), 25);
+    }
+
+    #[test]
+    fn test_code_frame_missing_file() {
+        let location = SourceLocation::new("does/not/exist.rs", 1, 1);
+
+        assert_eq!(location.code_frame(2), None);
+    }
+}
@@ -1,6 +1,9 @@
 //! Module for assertion chain and assertion handling
 
 mod assertion;
+pub mod chain;
+pub mod comparison;
+pub mod location;
 pub mod sentence;
 
 pub use assertion::{Assertion, AssertionStep, LogicalOp, TestSessionResult};
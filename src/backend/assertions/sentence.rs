@@ -71,7 +71,11 @@ impl AssertionSentence {
         // Convert the infinitive verb to the correct form based on plurality
         let conjugated_verb = self.conjugate_verb(is_plural);
 
-        let mut result = if self.negated {
+        let mut result = if self.negated && self.verb != "be" {
+            // Other verbs need an auxiliary: "does not have", not "has not"
+            let auxiliary = if is_plural { "do" } else { "does" };
+            format!("{} not {} {}", auxiliary, self.verb, self.object)
+        } else if self.negated {
             // Place "not" after the conjugated verb for grammatical correctness
             format!("{} not {}", conjugated_verb, self.object)
         } else {
@@ -226,19 +230,19 @@ mod tests {
         assert_eq!(sentence.verb, "be");
         assert_eq!(sentence.object, "positive");
         assert_eq!(sentence.qualifiers.len(), 0);
-        assert_eq!(sentence.negated, false);
+        assert!(!sentence.negated);
     }
 
     #[test]
     fn test_with_negation() {
         let sentence = AssertionSentence::new("be", "positive").with_negation(true);
 
-        assert_eq!(sentence.negated, true);
+        assert!(sentence.negated);
 
         // Test chaining and toggle
         let toggled_sentence = sentence.with_negation(false);
 
-        assert_eq!(toggled_sentence.negated, false);
+        assert!(!toggled_sentence.negated);
     }
 
     #[test]
@@ -303,20 +307,20 @@ mod tests {
     #[test]
     fn test_is_plural_subject() {
         // Test singular subjects
-        assert_eq!(AssertionSentence::is_plural_subject("value"), false);
-        assert_eq!(AssertionSentence::is_plural_subject("number"), false);
-        assert_eq!(AssertionSentence::is_plural_subject("count"), false);
-        assert_eq!(AssertionSentence::is_plural_subject("item"), false);
+        assert!(!AssertionSentence::is_plural_subject("value"));
+        assert!(!AssertionSentence::is_plural_subject("number"));
+        assert!(!AssertionSentence::is_plural_subject("count"));
+        assert!(!AssertionSentence::is_plural_subject("item"));
 
         // Test plural subjects
-        assert_eq!(AssertionSentence::is_plural_subject("values"), true);
-        assert_eq!(AssertionSentence::is_plural_subject("numbers"), true);
-        assert_eq!(AssertionSentence::is_plural_subject("items"), true);
-        assert_eq!(AssertionSentence::is_plural_subject("lists"), true);
+        assert!(AssertionSentence::is_plural_subject("values"));
+        assert!(AssertionSentence::is_plural_subject("numbers"));
+        assert!(AssertionSentence::is_plural_subject("items"));
+        assert!(AssertionSentence::is_plural_subject("lists"));
 
         // Test common plural variable names
-        assert_eq!(AssertionSentence::is_plural_subject("data"), true);
-        assert_eq!(AssertionSentence::is_plural_subject("children"), true);
+        assert!(AssertionSentence::is_plural_subject("data"));
+        assert!(AssertionSentence::is_plural_subject("children"));
     }
 
     #[test]
@@ -408,6 +412,11 @@ mod tests {
         assert_eq!(negated.format_with_conjugation("value"), "is not positive");
         assert_eq!(negated.format_with_conjugation("values"), "are not positive");
 
+        // Other verbs are negated with "does not" / "do not"
+        let negated_have = AssertionSentence::new("have", "length 3").with_negation(true);
+        assert_eq!(negated_have.format_with_conjugation("value"), "does not have length 3");
+        assert_eq!(negated_have.format_with_conjugation("values"), "do not have length 3");
+
         // Test with qualifiers - Note: we need to clone since with_qualifier consumes self
         let qualified = sentence.clone().with_qualifier("always");
         assert_eq!(qualified.format_with_conjugation("value"), "is positive always");
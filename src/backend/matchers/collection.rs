@@ -1,5 +1,7 @@
 use crate::backend::Assertion;
+use crate::backend::assertions::comparison::Comparison;
 use crate::backend::assertions::sentence::AssertionSentence;
+use std::collections::{BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque};
 use std::fmt::Debug;
 
 /// Define the primary matcher trait for collections
@@ -9,143 +11,167 @@ pub trait CollectionMatchers<T> {
     fn to_contain<U: PartialEq<T> + Debug>(self, expected: U) -> Self;
     fn to_contain_all_of<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self;
     fn to_equal_collection<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self;
-}
 
-/// Helper trait for types that can be examined as collections
-trait AsCollection {
-    type Item;
+    /// Check that the collection holds exactly the expected elements, with the same counts, in any order
+    fn to_contain_exactly_in_any_order<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self;
 
-    fn is_empty(&self) -> bool;
-    fn length(&self) -> usize;
-    fn contains_item<U>(&self, item: &U) -> bool
-    where
-        U: PartialEq<Self::Item>;
-    fn contains_all_items<U>(&self, items: &[U]) -> bool
-    where
-        U: PartialEq<Self::Item>;
-    fn equals_items<U>(&self, other: &[U]) -> bool
-    where
-        U: PartialEq<Self::Item>;
-}
+    /// Check that the expected elements appear in this order, not necessarily next to each other
+    fn to_contain_in_order<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self;
 
-// Implement AsCollection for slice references
-impl<T: PartialEq> AsCollection for &[T] {
-    type Item = T;
+    /// Check that the collection begins with the expected elements
+    fn to_start_with_elements<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self;
 
-    fn is_empty(&self) -> bool {
-        <[T]>::is_empty(self)
-    }
+    /// Check that the collection ends with the expected elements
+    fn to_end_with_elements<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self;
 
-    fn length(&self) -> usize {
-        self.len()
-    }
-
-    fn contains_item<U>(&self, item: &U) -> bool
+    /// Check that each element is less than or equal to the next one
+    fn to_be_sorted(self) -> Self
     where
-        U: PartialEq<Self::Item>,
-    {
-        self.iter().any(|x| item == x)
-    }
+        T: PartialOrd;
 
-    fn contains_all_items<U>(&self, items: &[U]) -> bool
+    /// Check that each element is greater than or equal to the next one
+    fn to_be_sorted_descending(self) -> Self
     where
-        U: PartialEq<Self::Item>,
-    {
-        items.iter().all(|item| self.contains_item(item))
-    }
+        T: PartialOrd;
 
-    fn equals_items<U>(&self, other: &[U]) -> bool
-    where
-        U: PartialEq<Self::Item>,
-    {
-        if self.len() != other.len() {
-            return false;
-        }
+    /// Check that the keys of the elements are in ascending order
+    fn to_be_sorted_by_key<K: PartialOrd + Debug, F: Fn(&T) -> K>(self, key: F) -> Self;
 
-        self.iter().zip(other.iter()).all(|(a, b)| b == a)
-    }
+    /// Check that no element appears twice
+    fn to_have_unique_elements(self) -> Self;
+
+    /// Check that every element is one of the expected elements
+    fn to_be_subset_of<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self;
+
+    /// Check that every expected element is in the collection
+    fn to_be_superset_of<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self;
 }
 
-// Implement AsCollection for Vec references
-impl<T: PartialEq> AsCollection for &Vec<T> {
-    type Item = T;
+/// Entry point of the collection matchers for any `IntoIterator` value
+///
+/// Standard collections work with `CollectionMatchers` directly; ranges, iterator adapters and other iterables are
+/// collected into a `Vec` first: `expect!(1..4).collected().to_contain(2)`.
+pub trait CollectModifier<I: IntoIterator> {
+    /// Continue the chain on the items of the value, collected into a `Vec`
+    fn collected(self) -> Assertion<Vec<I::Item>>;
+}
+
+/// Helper trait for types that can be examined as collections
+///
+/// Only `items` is required; the other checks are derived from it, and the standard collections override `length` and
+/// `is_empty` with their native `len` and `is_empty`.
+pub(crate) trait AsCollection {
+    type Item;
+
+    fn items(&self) -> Vec<&Self::Item>;
 
     fn is_empty(&self) -> bool {
-        Vec::is_empty(self)
+        self.length() == 0
     }
 
     fn length(&self) -> usize {
-        self.len()
+        self.items().len()
     }
 
     fn contains_item<U>(&self, item: &U) -> bool
     where
         U: PartialEq<Self::Item>,
     {
-        self.iter().any(|x| item == x)
+        self.items().into_iter().any(|x| item == x)
     }
 
     fn contains_all_items<U>(&self, items: &[U]) -> bool
     where
         U: PartialEq<Self::Item>,
     {
-        items.iter().all(|item| self.contains_item(item))
+        let actual = self.items();
+        items.iter().all(|item| actual.iter().any(|x| item == *x))
     }
 
     fn equals_items<U>(&self, other: &[U]) -> bool
     where
         U: PartialEq<Self::Item>,
     {
-        if self.len() != other.len() {
+        if self.length() != other.len() {
             return false;
         }
 
-        self.iter().zip(other.iter()).all(|(a, b)| b == a)
+        self.items().into_iter().zip(other.iter()).all(|(a, b)| b == a)
     }
 }
 
-// Implement AsCollection for owned Vecs
-impl<T: PartialEq> AsCollection for Vec<T> {
-    type Item = T;
-
-    fn is_empty(&self) -> bool {
-        Vec::is_empty(self)
-    }
+// Implement AsCollection for the standard collections, owned or borrowed
+macro_rules! impl_as_collection {
+    ($(<$($param:tt),*> $collection:ty),* $(,)?) => {
+        $(
+            impl<$($param),*> AsCollection for $collection {
+                type Item = T;
+
+                fn items(&self) -> Vec<&Self::Item> {
+                    self.iter().collect()
+                }
+
+                fn is_empty(&self) -> bool {
+                    <$collection>::is_empty(self)
+                }
+
+                fn length(&self) -> usize {
+                    <$collection>::len(self)
+                }
+            }
+
+            impl<$($param),*> AsCollection for &$collection {
+                type Item = T;
+
+                fn items(&self) -> Vec<&Self::Item> {
+                    self.iter().collect()
+                }
+
+                fn is_empty(&self) -> bool {
+                    <$collection>::is_empty(*self)
+                }
+
+                fn length(&self) -> usize {
+                    <$collection>::len(*self)
+                }
+            }
+        )*
+    };
+}
 
-    fn length(&self) -> usize {
-        self.len()
-    }
+impl_as_collection!(
+    <T> Vec<T>,
+    <T> VecDeque<T>,
+    <T> LinkedList<T>,
+    <T, S> HashSet<T, S>,
+    <T> BTreeSet<T>,
+    <T> BinaryHeap<T>,
+);
+
+// Arrays need a const parameter, and slices only exist behind a reference
+impl<T, const N: usize> AsCollection for [T; N] {
+    type Item = T;
 
-    fn contains_item<U>(&self, item: &U) -> bool
-    where
-        U: PartialEq<Self::Item>,
-    {
-        self.iter().any(|x| item == x)
+    fn items(&self) -> Vec<&Self::Item> {
+        self.iter().collect()
     }
 
-    fn contains_all_items<U>(&self, items: &[U]) -> bool
-    where
-        U: PartialEq<Self::Item>,
-    {
-        items.iter().all(|item| self.contains_item(item))
+    fn is_empty(&self) -> bool {
+        N == 0
     }
 
-    fn equals_items<U>(&self, other: &[U]) -> bool
-    where
-        U: PartialEq<Self::Item>,
-    {
-        if self.len() != other.len() {
-            return false;
-        }
-
-        self.iter().zip(other.iter()).all(|(a, b)| b == a)
+    fn length(&self) -> usize {
+        N
     }
 }
 
-// Implement AsCollection for array references
-impl<T: PartialEq, const N: usize> AsCollection for &[T; N] {
+impl<T, const N: usize> AsCollection for &[T; N] {
     type Item = T;
 
+    fn items(&self) -> Vec<&Self::Item> {
+        self.iter().collect()
+    }
+
     fn is_empty(&self) -> bool {
         N == 0
     }
@@ -153,30 +179,42 @@ impl<T: PartialEq, const N: usize> AsCollection for &[T; N] {
     fn length(&self) -> usize {
         N
     }
+}
 
-    fn contains_item<U>(&self, item: &U) -> bool
-    where
-        U: PartialEq<Self::Item>,
-    {
-        self.iter().any(|x| item == x)
+impl<T> AsCollection for &[T] {
+    type Item = T;
+
+    fn items(&self) -> Vec<&Self::Item> {
+        self.iter().collect()
     }
 
-    fn contains_all_items<U>(&self, items: &[U]) -> bool
-    where
-        U: PartialEq<Self::Item>,
-    {
-        items.iter().all(|item| self.contains_item(item))
+    fn is_empty(&self) -> bool {
+        <[T]>::is_empty(self)
     }
 
-    fn equals_items<U>(&self, other: &[U]) -> bool
-    where
-        U: PartialEq<Self::Item>,
-    {
-        if N != other.len() {
-            return false;
-        }
+    fn length(&self) -> usize {
+        <[T]>::len(self)
+    }
+}
 
-        self.iter().zip(other.iter()).all(|(a, b)| b == a)
+impl<I: IntoIterator + Clone> CollectModifier<I> for Assertion<I> {
+    fn collected(self) -> Assertion<Vec<I::Item>> {
+        let mut result = self;
+        result.mark_as_intermediate();
+
+        // The items are the same value seen as a collection, so the subject does not change
+        return Assertion {
+            value: result.value.clone().into_iter().collect(),
+            expr_str: result.expr_str,
+            subject: result.subject.clone(),
+            unwrapped: result.unwrapped.clone(),
+            negated: result.negated,
+            steps: result.steps.clone(),
+            chain: result.expression(),
+            in_chain: true,
+            is_final: true,
+            location: result.location,
+        };
     }
 }
 
@@ -188,9 +226,10 @@ where
 {
     fn to_be_empty(self) -> Self {
         let result = self.value.is_empty();
+        let actual_length = self.value.length();
         let sentence = AssertionSentence::new("be", "empty");
 
-        return self.add_step(sentence, result);
+        return self.add_step(sentence, result).with_received(format!("length {}", actual_length));
     }
 
     fn to_have_length(self, expected: usize) -> Self {
@@ -198,7 +237,7 @@ where
         let result = actual_length == expected;
         let sentence = AssertionSentence::new("have", format!("length {}", expected));
 
-        return self.add_step(sentence, result);
+        return self.add_step(sentence, result).with_received(format!("length {}", actual_length));
     }
 
     fn to_contain<U: PartialEq<T> + Debug>(self, expected: U) -> Self {
@@ -219,14 +258,195 @@ where
         let result = self.value.equals_items(expected);
 
         // Different message if lengths don't match
-        if self.value.length() != expected.len() {
-            let sentence = AssertionSentence::new("equal", format!("collection {:?} (different lengths)", expected));
-            return self.add_step(sentence, result);
+        let sentence = if self.value.length() != expected.len() {
+            AssertionSentence::new("equal", format!("collection {:?} (different lengths)", expected))
+        } else {
+            AssertionSentence::new("equal", format!("collection {:?}", expected))
+        };
+
+        // Only a failed positive comparison has differences worth showing
+        if !result && !self.negated {
+            let comparison = Comparison::of_sequences(expected, self.value.items());
+            return self.add_step(sentence, result).with_comparison(comparison);
         }
 
-        let sentence = AssertionSentence::new("equal", format!("collection {:?}", expected));
         return self.add_step(sentence, result);
     }
+
+    fn to_contain_exactly_in_any_order<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self {
+        let items = self.value.items();
+        let (missing, unexpected) = unmatched_elements(&items, expected);
+        let result = missing.is_empty() && unexpected.is_empty();
+        let sentence = AssertionSentence::new("contain", format!("exactly {:?} in any order", expected));
+
+        let mut differences = Vec::new();
+        if !missing.is_empty() {
+            differences.push(format!("missing {:?}", missing));
+        }
+        if !unexpected.is_empty() {
+            differences.push(format!("unexpected {:?}", unexpected));
+        }
+        let received = if result { format!("{:?}", items) } else { differences.join(", ") };
+
+        return self.add_step(sentence, result).with_received(received);
+    }
+
+    fn to_contain_in_order<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self {
+        let items = self.value.items();
+
+        // Match the expected elements greedily, each after the previous match
+        let mut position = 0;
+        let mut matched = 0;
+        for wanted in expected {
+            match items[position..].iter().position(|item| wanted == *item) {
+                Some(offset) => {
+                    position += offset + 1;
+                    matched += 1;
+                }
+                None => break,
+            }
+        }
+
+        let result = matched == expected.len();
+        let sentence = AssertionSentence::new("contain", format!("{:?} in order", expected));
+
+        let received = match expected.get(matched) {
+            None => format!("{:?} in order", expected),
+            Some(wanted) if matched == 0 => format!("no {:?}", wanted),
+            Some(wanted) => format!("{:?} in order, then no {:?} after index {}", &expected[..matched], wanted, position - 1),
+        };
+
+        return self.add_step(sentence, result).with_received(received);
+    }
+
+    fn to_start_with_elements<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self {
+        let items = self.value.items();
+        let result = items.len() >= expected.len() && expected.iter().zip(items.iter()).all(|(wanted, item)| wanted == *item);
+        let sentence = AssertionSentence::new("start with", format!("elements {:?}", expected));
+
+        let prefix = &items[..expected.len().min(items.len())];
+        return self.add_step(sentence, result).with_received(format!("starts with {:?}", prefix));
+    }
+
+    fn to_end_with_elements<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self {
+        let items = self.value.items();
+        let start = items.len().saturating_sub(expected.len());
+        let result = items.len() >= expected.len() && expected.iter().zip(items[start..].iter()).all(|(wanted, item)| wanted == *item);
+        let sentence = AssertionSentence::new("end with", format!("elements {:?}", expected));
+
+        let suffix = &items[start..];
+        return self.add_step(sentence, result).with_received(format!("ends with {:?}", suffix));
+    }
+
+    fn to_be_sorted(self) -> Self
+    where
+        T: PartialOrd,
+    {
+        let items = self.value.items();
+        let disorder = first_disorder(&items, |previous, next| previous <= next);
+        let sentence = AssertionSentence::new("be", "sorted");
+
+        let received = describe_disorder(disorder, |index| format!("{:?}", items[index]));
+        return self.add_step(sentence, disorder.is_none()).with_received(received);
+    }
+
+    fn to_be_sorted_descending(self) -> Self
+    where
+        T: PartialOrd,
+    {
+        let items = self.value.items();
+        let disorder = first_disorder(&items, |previous, next| previous >= next);
+        let sentence = AssertionSentence::new("be", "sorted in descending order");
+
+        let received = describe_disorder(disorder, |index| format!("{:?}", items[index]));
+        return self.add_step(sentence, disorder.is_none()).with_received(received);
+    }
+
+    fn to_be_sorted_by_key<K: PartialOrd + Debug, F: Fn(&T) -> K>(self, key: F) -> Self {
+        let keys: Vec<K> = self.value.items().into_iter().map(key).collect();
+        let disorder = first_disorder(&keys, |previous, next| previous <= next);
+        let sentence = AssertionSentence::new("be", "sorted by key");
+
+        let received = describe_disorder(disorder, |index| format!("key {:?}", keys[index]));
+        return self.add_step(sentence, disorder.is_none()).with_received(received);
+    }
+
+    fn to_have_unique_elements(self) -> Self {
+        let items = self.value.items();
+
+        // Group the indices of equal elements, keeping the groups with more than one index
+        let mut seen: Vec<(usize, Vec<usize>)> = Vec::new();
+        for (index, item) in items.iter().enumerate() {
+            match seen.iter_mut().find(|(first, _)| items[*first] == *item) {
+                Some((_, indices)) => indices.push(index),
+                None => seen.push((index, vec![index])),
+            }
+        }
+        let duplicates: Vec<String> = seen
+            .iter()
+            .filter(|(_, indices)| indices.len() > 1)
+            .map(|(first, indices)| {
+                let positions: Vec<String> = indices.iter().map(|index| format!("[{}]", index)).collect();
+                return format!("{:?} at {}", items[*first], positions.join(", "));
+            })
+            .collect();
+
+        let result = duplicates.is_empty();
+        let sentence = AssertionSentence::new("have", "unique elements");
+        let received = if result { format!("{} unique elements", items.len()) } else { format!("duplicated {}", duplicates.join("; ")) };
+
+        return self.add_step(sentence, result).with_received(received);
+    }
+
+    fn to_be_subset_of<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self {
+        let items = self.value.items();
+        let unexpected: Vec<&T> = items.iter().copied().filter(|item| !expected.iter().any(|allowed| allowed == *item)).collect();
+        let result = unexpected.is_empty();
+        let sentence = AssertionSentence::new("be", format!("a subset of {:?}", expected));
+
+        let received = if result { format!("{:?}", items) } else { format!("unexpected {:?}", unexpected) };
+        return self.add_step(sentence, result).with_received(received);
+    }
+
+    fn to_be_superset_of<U: PartialEq<T> + Debug>(self, expected: &[U]) -> Self {
+        let items = self.value.items();
+        let missing: Vec<&U> = expected.iter().filter(|wanted| !items.iter().any(|item| *wanted == *item)).collect();
+        let result = missing.is_empty();
+        let sentence = AssertionSentence::new("be", format!("a superset of {:?}", expected));
+
+        let received = if result { format!("{:?}", items) } else { format!("missing {:?}", missing) };
+        return self.add_step(sentence, result).with_received(received);
+    }
+}
+
+/// Pair up actual and expected elements regardless of order, returning the expected elements left without a match
+/// and the actual elements left without a match
+fn unmatched_elements<'a, T, U: PartialEq<T>>(items: &[&'a T], expected: &'a [U]) -> (Vec<&'a U>, Vec<&'a T>) {
+    let mut used = vec![false; items.len()];
+    let mut missing = Vec::new();
+
+    for wanted in expected {
+        match (0..items.len()).find(|&index| !used[index] && wanted == items[index]) {
+            Some(index) => used[index] = true,
+            None => missing.push(wanted),
+        }
+    }
+
+    let unexpected = items.iter().zip(used).filter(|(_, used)| !used).map(|(item, _)| *item).collect();
+    return (missing, unexpected);
+}
+
+/// Find the first index whose element is out of order with the previous one
+fn first_disorder<V>(values: &[V], in_order: impl Fn(&V, &V) -> bool) -> Option<usize> {
+    return (1..values.len()).find(|&index| !in_order(&values[index - 1], &values[index]));
+}
+
+/// Describe where the order breaks, e.g. "out of order at [3]: 3 after 5"
+fn describe_disorder(disorder: Option<usize>, render: impl Fn(usize) -> String) -> String {
+    return match disorder {
+        Some(index) => format!("out of order at [{}]: {} after {}", index, render(index), render(index - 1)),
+        None => "in order".to_string(),
+    };
 }
 
 /// Extension trait for adding helper methods to collections
@@ -408,4 +628,174 @@ mod tests {
         let slice = empty.as_slice();
         expect!(slice).not().to_be_empty();
     }
+
+    #[test]
+    fn test_failed_collection_equality_captures_comparison() {
+        use crate::backend::Comparison;
+
+        let mut result = expect!(vec![1, 5, 3]).to_equal_collection(&[1, 2, 3]);
+        result.mark_as_intermediate();
+
+        let expected = vec!["1".to_string(), "2".to_string(), "3".to_string()];
+        let actual = vec!["1".to_string(), "5".to_string(), "3".to_string()];
+        assert_eq!(result.steps[0].comparison, Some(Comparison::Sequence { expected, actual }));
+    }
+
+    /// The "received" line recorded by the last step of a failed chain
+    fn received_of<T>(mut assertion: crate::backend::Assertion<T>) -> String {
+        assertion.mark_as_intermediate();
+        return assertion.steps.last().and_then(|step| step.received.clone()).unwrap_or_default();
+    }
+
+    #[test]
+    fn test_contain_exactly_in_any_order() {
+        crate::Reporter::disable_deduplication();
+
+        let numbers = vec![3, 1, 2, 1];
+
+        expect!(&numbers).to_contain_exactly_in_any_order(&[1, 1, 2, 3]);
+        expect!(&numbers).not().to_contain_exactly_in_any_order(&[1, 2, 3]);
+
+        let received = received_of(expect!(&numbers).to_contain_exactly_in_any_order(&[1, 2, 3, 4]));
+        assert_eq!(received, "missing [4], unexpected [1]");
+    }
+
+    #[test]
+    fn test_contain_in_order() {
+        crate::Reporter::disable_deduplication();
+
+        let numbers = vec![1, 5, 2, 7, 3];
+
+        expect!(&numbers).to_contain_in_order(&[1, 2, 3]);
+        expect!(&numbers).not().to_contain_in_order(&[2, 1]);
+
+        let received = received_of(expect!(&numbers).to_contain_in_order(&[5, 7, 2]));
+        assert_eq!(received, "[5, 7] in order, then no 2 after index 3");
+    }
+
+    #[test]
+    fn test_start_and_end_with_elements() {
+        crate::Reporter::disable_deduplication();
+
+        let numbers = vec![1, 2, 3, 4];
+
+        expect!(&numbers).to_start_with_elements(&[1, 2]).and().to_end_with_elements(&[3, 4]);
+        expect!(&numbers).not().to_start_with_elements(&[2]).and().not().to_end_with_elements(&[1, 2, 3, 4, 5]);
+
+        assert_eq!(received_of(expect!(&numbers).to_start_with_elements(&[2, 3])), "starts with [1, 2]");
+        assert_eq!(received_of(expect!(&numbers).to_end_with_elements(&[2])), "ends with [4]");
+    }
+
+    #[test]
+    fn test_sorted() {
+        crate::Reporter::disable_deduplication();
+
+        let ascending = vec![1, 2, 2, 5];
+        let descending = vec![9, 4, 4, 0];
+        let words = vec!["fig", "kiwi", "banana"];
+
+        expect!(&ascending).to_be_sorted();
+        expect!(&descending).to_be_sorted_descending().and().not().to_be_sorted();
+        expect!(&words).to_be_sorted_by_key(|word| word.len());
+        expect!(Vec::<i32>::new()).to_be_sorted();
+
+        assert_eq!(received_of(expect!(vec![1, 5, 3]).to_be_sorted()), "out of order at [2]: 3 after 5");
+        assert_eq!(received_of(expect!(&words).to_be_sorted()), "out of order at [2]: \"banana\" after \"kiwi\"");
+        assert_eq!(
+            received_of(expect!(&words).to_be_sorted_by_key(|word| -(word.len() as i32))),
+            "out of order at [1]: key -4 after key -3"
+        );
+    }
+
+    #[test]
+    fn test_unique_elements() {
+        crate::Reporter::disable_deduplication();
+
+        expect!(vec![1, 2, 3]).to_have_unique_elements();
+        expect!(vec![1, 2, 1]).not().to_have_unique_elements();
+
+        let received = received_of(expect!(vec![4, 2, 4, 2, 4]).to_have_unique_elements());
+        assert_eq!(received, "duplicated 4 at [0], [2], [4]; 2 at [1], [3]");
+    }
+
+    #[test]
+    fn test_subset_and_superset() {
+        crate::Reporter::disable_deduplication();
+
+        let numbers = vec![1, 2, 3];
+
+        expect!(&numbers).to_be_subset_of(&[0, 1, 2, 3, 4]).and().to_be_superset_of(&[3, 1]);
+        expect!(&numbers).not().to_be_subset_of(&[1, 2]).and().not().to_be_superset_of(&[4]);
+
+        assert_eq!(received_of(expect!(&numbers).to_be_subset_of(&[1])), "unexpected [2, 3]");
+        assert_eq!(received_of(expect!(&numbers).to_be_superset_of(&[1, 5, 6])), "missing [5, 6]");
+    }
+
+    #[test]
+    #[should_panic(expected = "be sorted\n  expected: be sorted\n  received: out of order at [2]: 3 after 5")]
+    fn test_unsorted_fails() {
+        let _assertion = expect!(vec![1, 5, 3]).to_be_sorted();
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_standard_collections() {
+        use std::collections::{BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque};
+
+        crate::Reporter::disable_deduplication();
+
+        let deque: VecDeque<i32> = VecDeque::from(vec![1, 2, 3]);
+        let list: LinkedList<i32> = [1, 2, 3].into_iter().collect();
+        let hash_set: HashSet<i32> = [3, 1, 2].into_iter().collect();
+        let tree_set: BTreeSet<i32> = [3, 1, 2].into_iter().collect();
+        let heap: BinaryHeap<i32> = [3, 1, 2].into_iter().collect();
+
+        expect!(&deque).to_equal_collection(&[1, 2, 3]).and().to_be_sorted();
+        expect!(deque.clone()).to_contain(2);
+        expect!(&list).to_start_with_elements(&[1, 2]).and().to_have_length(3);
+        expect!(&hash_set).to_contain_exactly_in_any_order(&[1, 2, 3]).and().not().to_contain(4);
+        expect!(&tree_set).to_equal_collection(&[1, 2, 3]).and().to_have_unique_elements();
+        expect!(&heap).to_contain_all_of(&[1, 2, 3]).and().not().to_be_empty();
+        expect!([1, 2, 3]).to_contain(2);
+    }
+
+    #[test]
+    fn test_native_lengths() {
+        use std::collections::{BinaryHeap, VecDeque};
+
+        crate::Reporter::disable_deduplication();
+
+        let empty: [i32; 0] = [];
+        let slice: &[i32] = &[1, 2];
+        let deque = VecDeque::from(vec![1, 2, 3]);
+
+        expect!(VecDeque::<i32>::new()).to_be_empty();
+        expect!(&deque).to_have_length(3).and().not().to_be_empty();
+        expect!(BinaryHeap::from(vec![2, 1])).to_have_length(2);
+        expect!(empty).to_be_empty().and().to_have_length(0);
+        expect!(&[1, 2, 3]).to_have_length(3);
+        expect!(slice).to_have_length(2).and().to_equal_collection(&[1, 2]);
+    }
+
+    #[test]
+    fn test_sets_with_custom_hasher() {
+        use std::collections::HashSet;
+        use std::hash::BuildHasherDefault;
+
+        crate::Reporter::disable_deduplication();
+
+        let mut set: HashSet<&str, BuildHasherDefault<std::collections::hash_map::DefaultHasher>> = HashSet::default();
+        set.insert("a");
+
+        expect!(&set).to_contain("a").and().to_have_length(1);
+    }
+
+    #[test]
+    fn test_collected() {
+        crate::Reporter::disable_deduplication();
+
+        expect!(1..4).collected().to_equal_collection(&[1, 2, 3]);
+        expect!("a,b,c".split(',')).collected().to_contain("b").and().to_have_length(3);
+        expect!((1..=10).filter(|n| n % 3 == 0)).collected().to_contain_exactly_in_any_order(&[9, 3, 6]);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::backend::Assertion;
+use crate::backend::assertions::comparison::Comparison;
 use crate::backend::assertions::sentence::AssertionSentence;
 use std::fmt::Debug;
 
@@ -43,6 +44,12 @@ where
         let result = self.value.equals(&expected);
         let sentence = AssertionSentence::new("be", format!("equal to {:?}", expected));
 
+        // Only a failed positive comparison has differences worth showing
+        if !result && !self.negated {
+            let comparison = Comparison::of_values(&expected, &self.value);
+            return self.add_step(sentence, result).with_comparison(comparison);
+        }
+
         return self.add_step(sentence, result);
     }
 }
@@ -67,8 +74,8 @@ mod tests {
         expect!("hello").not().to_equal("world");
 
         // Test with floating point
-        expect!(3.14).to_equal(3.14);
-        expect!(3.14).not().to_equal(2.71);
+        expect!(1.5).to_equal(1.5);
+        expect!(1.5).not().to_equal(2.5);
 
         // Test with boolean
         expect!(true).to_equal(true);
@@ -90,4 +97,32 @@ mod tests {
         let _assertion = expect!(42).not().to_equal(42);
         std::hint::black_box(_assertion);
     }
+
+    #[test]
+    fn test_failed_equality_captures_comparison() {
+        use crate::backend::Comparison;
+
+        let mut result = expect!("a\nb").to_equal("a\nc");
+        result.mark_as_intermediate();
+        assert_eq!(result.steps[0].comparison, Some(Comparison::Text { expected: "a\nc".to_string(), actual: "a\nb".to_string() }));
+
+        // Passing and negated comparisons have nothing to diff
+        let mut result = expect!(1).not().to_equal(1);
+        result.mark_as_intermediate();
+        assert_eq!(result.steps[0].comparison, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "received: \"a\\nb\"\n  Difference (- expected, + received):\n    a\n  - c\n  + b")]
+    fn test_failure_message_shows_diff() {
+        let _assertion = expect!("a\nb").to_equal("a\nc");
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "at src/backend/matchers/equality.rs:")]
+    fn test_failure_reports_location() {
+        let _assertion = expect!(1).to_equal(2);
+        std::hint::black_box(_assertion);
+    }
 }
@@ -0,0 +1,424 @@
+use crate::backend::Assertion;
+use crate::backend::assertions::sentence::AssertionSentence;
+use std::fmt::Debug;
+use std::ops::{Div, Sub};
+
+/// Trait for floating-point assertions
+pub trait FloatMatchers<T> {
+    /// Check if the value is within an absolute tolerance of the expected value
+    fn to_be_close_to(self, expected: T, abs_tol: T) -> Self;
+
+    /// Check if the value is within a tolerance relative to the larger magnitude of both values
+    fn to_be_within_relative(self, expected: T, rel_tol: T) -> Self;
+
+    /// Check if the value is at most `ulps` representable floats away from the expected value
+    fn to_be_within_ulps(self, expected: T, ulps: u64) -> Self;
+
+    fn to_be_nan(self) -> Self;
+    fn to_be_finite(self) -> Self;
+    fn to_be_infinite(self) -> Self;
+
+    /// Check if the value is strictly greater than zero (NaN is never positive)
+    fn to_be_positive(self) -> Self;
+
+    /// Check if the value is strictly less than zero (NaN is never negative)
+    fn to_be_negative(self) -> Self;
+
+    /// Check if the sign bit is clear, which also holds for `+0.0` and positive NaN
+    fn to_be_sign_positive(self) -> Self;
+
+    /// Check if the sign bit is set, which also holds for `-0.0` and negative NaN
+    fn to_be_sign_negative(self) -> Self;
+}
+
+/// Trait for element-wise floating-point assertions on collections
+pub trait FloatCollectionMatchers<T> {
+    /// Check if every element is within an absolute tolerance of the element at the same index
+    fn to_be_close_to_collection(self, expected: &[T], abs_tol: T) -> Self;
+}
+
+/// Helper trait for primitive floating-point types
+trait Float: Copy + PartialOrd + Debug + Sub<Output = Self> + Div<Output = Self> {
+    const ZERO: Self;
+
+    fn abs_value(self) -> Self;
+    fn max_value(self, other: Self) -> Self;
+    fn is_nan_value(self) -> bool;
+    fn is_finite_value(self) -> bool;
+    fn is_infinite_value(self) -> bool;
+    fn is_sign_positive_value(self) -> bool;
+
+    /// Map the bits to an integer whose ordering matches the ordering of the floats
+    fn ordered_bits(self) -> i128;
+}
+
+/// Helper trait for float-like types, giving access to the underlying float
+trait AsFloat {
+    type Number: Float;
+
+    fn number(&self) -> Self::Number;
+}
+
+/// Helper trait for collections of floats
+trait AsFloatSlice {
+    type Number: Float;
+
+    fn as_float_slice(&self) -> &[Self::Number];
+}
+
+// Implement the helper traits for a float primitive and references to it
+macro_rules! impl_as_float {
+    ($($float:ty => $bits:ty),*) => {
+        $(
+            impl Float for $float {
+                const ZERO: Self = 0.0;
+
+                fn abs_value(self) -> Self {
+                    self.abs()
+                }
+
+                fn max_value(self, other: Self) -> Self {
+                    self.max(other)
+                }
+
+                fn is_nan_value(self) -> bool {
+                    self.is_nan()
+                }
+
+                fn is_finite_value(self) -> bool {
+                    self.is_finite()
+                }
+
+                fn is_infinite_value(self) -> bool {
+                    self.is_infinite()
+                }
+
+                fn is_sign_positive_value(self) -> bool {
+                    self.is_sign_positive()
+                }
+
+                fn ordered_bits(self) -> i128 {
+                    let bits = self.to_bits() as $bits;
+                    if bits < 0 { <$bits>::MIN as i128 - bits as i128 } else { bits as i128 }
+                }
+            }
+
+            impl AsFloat for $float {
+                type Number = $float;
+
+                fn number(&self) -> $float {
+                    *self
+                }
+            }
+
+            impl AsFloat for &$float {
+                type Number = $float;
+
+                fn number(&self) -> $float {
+                    **self
+                }
+            }
+
+            impl AsFloatSlice for Vec<$float> {
+                type Number = $float;
+
+                fn as_float_slice(&self) -> &[$float] {
+                    self.as_slice()
+                }
+            }
+
+            impl AsFloatSlice for &Vec<$float> {
+                type Number = $float;
+
+                fn as_float_slice(&self) -> &[$float] {
+                    self.as_slice()
+                }
+            }
+
+            impl AsFloatSlice for &[$float] {
+                type Number = $float;
+
+                fn as_float_slice(&self) -> &[$float] {
+                    self
+                }
+            }
+
+            impl<const N: usize> AsFloatSlice for &[$float; N] {
+                type Number = $float;
+
+                fn as_float_slice(&self) -> &[$float] {
+                    self.as_slice()
+                }
+            }
+        )*
+    };
+}
+
+impl_as_float!(f32 => i32, f64 => i64);
+
+/// Absolute difference between two floats, NaN when either is NaN or both are the same infinity
+fn difference<T: Float>(actual: T, expected: T) -> T {
+    if actual == expected {
+        return T::ZERO;
+    }
+
+    return (actual - expected).abs_value();
+}
+
+/// Number of representable floats between two values, `None` when either is NaN
+fn ulps_distance<T: Float>(actual: T, expected: T) -> Option<u64> {
+    if actual.is_nan_value() || expected.is_nan_value() {
+        return None;
+    }
+
+    let distance = (actual.ordered_bits() - expected.ordered_bits()).unsigned_abs();
+    return Some(u64::try_from(distance).unwrap_or(u64::MAX));
+}
+
+// Single implementation for any type that implements AsFloat
+impl<T, V> FloatMatchers<T> for Assertion<V>
+where
+    T: Float,
+    V: AsFloat<Number = T> + Debug + Clone,
+{
+    fn to_be_close_to(self, expected: T, abs_tol: T) -> Self {
+        let actual = self.value.number();
+        let diff = difference(actual, expected);
+        let result = actual == expected || diff <= abs_tol;
+        let sentence = AssertionSentence::new("be", format!("close to {:?}", expected))
+            .with_qualifier(format!("within {:?} (difference {:?})", abs_tol, diff));
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_be_within_relative(self, expected: T, rel_tol: T) -> Self {
+        let actual = self.value.number();
+        let diff = difference(actual, expected);
+        let scale = actual.abs_value().max_value(expected.abs_value());
+        let relative = if diff == T::ZERO { T::ZERO } else { diff / scale };
+        let result = actual == expected || relative <= rel_tol;
+        let sentence = AssertionSentence::new("be", format!("within relative tolerance {:?} of {:?}", rel_tol, expected))
+            .with_qualifier(format!("(relative difference {:?})", relative));
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_be_within_ulps(self, expected: T, ulps: u64) -> Self {
+        let distance = ulps_distance(self.value.number(), expected);
+        let result = distance.is_some_and(|d| d <= ulps);
+        let qualifier = match distance {
+            Some(d) => format!("(distance {} ULPs)", d),
+            None => "(distance undefined for NaN)".to_string(),
+        };
+        let sentence = AssertionSentence::new("be", format!("within {} ULPs of {:?}", ulps, expected)).with_qualifier(qualifier);
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_be_nan(self) -> Self {
+        let result = self.value.number().is_nan_value();
+        let sentence = AssertionSentence::new("be", "NaN");
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_be_finite(self) -> Self {
+        let result = self.value.number().is_finite_value();
+        let sentence = AssertionSentence::new("be", "finite");
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_be_infinite(self) -> Self {
+        let result = self.value.number().is_infinite_value();
+        let sentence = AssertionSentence::new("be", "infinite");
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_be_positive(self) -> Self {
+        let result = self.value.number() > T::ZERO;
+        let sentence = AssertionSentence::new("be", "positive");
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_be_negative(self) -> Self {
+        let result = self.value.number() < T::ZERO;
+        let sentence = AssertionSentence::new("be", "negative");
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_be_sign_positive(self) -> Self {
+        let result = self.value.number().is_sign_positive_value();
+        let sentence = AssertionSentence::new("have", "a positive sign");
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_be_sign_negative(self) -> Self {
+        let result = !self.value.number().is_sign_positive_value();
+        let sentence = AssertionSentence::new("have", "a negative sign");
+
+        return self.add_step(sentence, result);
+    }
+}
+
+// Single implementation for any collection of floats
+impl<T, V> FloatCollectionMatchers<T> for Assertion<V>
+where
+    T: Float,
+    V: AsFloatSlice<Number = T> + Debug + Clone,
+{
+    fn to_be_close_to_collection(self, expected: &[T], abs_tol: T) -> Self {
+        let actual = self.value.as_float_slice();
+        let sentence = AssertionSentence::new("be", format!("close to collection {:?}", expected));
+
+        if actual.len() != expected.len() {
+            let sentence = sentence.with_qualifier(format!("within {:?} (lengths {} and {})", abs_tol, actual.len(), expected.len()));
+            return self.add_step(sentence, false);
+        }
+
+        // Find the first element outside the tolerance
+        let mismatch = actual.iter().zip(expected.iter()).enumerate().find_map(|(index, (&a, &e))| {
+            let diff = difference(a, e);
+            if a == e || diff <= abs_tol { None } else { Some((index, a, e, diff)) }
+        });
+
+        let qualifier = match mismatch {
+            Some((index, a, e, diff)) => format!("within {:?} (index {}: {:?} vs {:?}, difference {:?})", abs_tol, index, a, e, diff),
+            None => format!("within {:?}", abs_tol),
+        };
+
+        return self.add_step(sentence.with_qualifier(qualifier), mismatch.is_none());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_float_to_be_close_to() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        // These should pass
+        expect!(0.1 + 0.2).to_be_close_to(0.3, 1e-9);
+        expect!(1.5f32).to_be_close_to(1.52, 0.05);
+        expect!(f64::INFINITY).to_be_close_to(f64::INFINITY, 1e-9);
+        expect!(1.0).not().to_be_close_to(1.1, 0.01);
+        expect!(f64::NAN).not().to_be_close_to(f64::NAN, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "be close to 1.0 within 0.01 (difference")]
+    fn test_not_close_fails() {
+        expect!(1.5).to_be_close_to(1.0, 0.01);
+    }
+
+    #[test]
+    fn test_float_to_be_within_relative() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        // These should pass
+        expect!(1_000_000.0).to_be_within_relative(1_000_001.0, 1e-5);
+        expect!(0.0).to_be_within_relative(0.0, 1e-12);
+        expect!(100.0).not().to_be_within_relative(110.0, 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "(relative difference 0.5)")]
+    fn test_not_within_relative_fails() {
+        expect!(1.0).to_be_within_relative(2.0, 0.1);
+    }
+
+    #[test]
+    fn test_float_to_be_within_ulps() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        let next = f64::from_bits(1.0f64.to_bits() + 1);
+
+        // These should pass
+        expect!(next).to_be_within_ulps(1.0, 1);
+        expect!(0.0).to_be_within_ulps(-0.0, 0);
+        expect!(f32::MIN_POSITIVE).to_be_within_ulps(-f32::MIN_POSITIVE, 1 << 24);
+        expect!(next).not().to_be_within_ulps(1.0, 0);
+        expect!(f64::NAN).not().to_be_within_ulps(f64::NAN, u64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "(distance 2 ULPs)")]
+    fn test_not_within_ulps_fails() {
+        let value = f64::from_bits(1.0f64.to_bits() + 2);
+        expect!(value).to_be_within_ulps(1.0, 1);
+    }
+
+    #[test]
+    fn test_float_classification() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        expect!(f64::NAN).to_be_nan();
+        expect!(1.0).not().to_be_nan();
+        expect!(1.0f32).to_be_finite();
+        expect!(f32::INFINITY).not().to_be_finite();
+        expect!(f64::NEG_INFINITY).to_be_infinite();
+        expect!(&2.5).not().to_be_infinite();
+    }
+
+    #[test]
+    #[should_panic(expected = "be NaN")]
+    fn test_number_to_be_nan_fails() {
+        expect!(1.0).to_be_nan();
+    }
+
+    #[test]
+    fn test_float_sign_checks() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        expect!(2.5).to_be_positive();
+        expect!(-2.5).to_be_negative();
+        expect!(0.0).not().to_be_positive().and().not().to_be_negative();
+        expect!(f64::NAN).not().to_be_positive();
+        expect!(-0.0).to_be_sign_negative();
+        expect!(0.0f32).to_be_sign_positive();
+    }
+
+    #[test]
+    #[should_panic(expected = "have a negative sign")]
+    fn test_positive_zero_sign_negative_fails() {
+        expect!(0.0).to_be_sign_negative();
+    }
+
+    #[test]
+    fn test_float_collection_close_to() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        let values = vec![0.1 + 0.2, 1.0 / 3.0];
+        let singles = [1.0f32, 2.0];
+
+        expect!(&values).to_be_close_to_collection(&[0.3, 0.333_333], 1e-6);
+        expect!(values.as_slice()).not().to_be_close_to_collection(&[0.3, 0.5], 1e-6);
+        expect!(&singles).to_be_close_to_collection(&[1.0, 2.0], 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "(index 1: 2.0 vs 2.5, difference 0.5)")]
+    fn test_collection_not_close_fails() {
+        let values = vec![1.0, 2.0];
+        expect!(values).to_be_close_to_collection(&[1.0, 2.5], 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "(lengths 2 and 3)")]
+    fn test_collection_different_lengths_fails() {
+        let values = vec![1.0, 2.0];
+        expect!(values).to_be_close_to_collection(&[1.0, 2.0, 3.0], 0.1);
+    }
+}
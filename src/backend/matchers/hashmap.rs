@@ -1,80 +1,179 @@
 use crate::backend::Assertion;
+use crate::backend::assertions::comparison::{Comparison, compare_rendered};
 use crate::backend::assertions::sentence::AssertionSentence;
 use std::borrow::Borrow;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
 
+/// Matchers for maps: `HashMap` with any hasher and `BTreeMap`, owned or borrowed
+///
+/// Keys are looked up with the map's own `get`, so a lookup key is `Hash + Eq` for a `HashMap` and `Ord` for a
+/// `BTreeMap`, see [`MapLookup`].
 pub trait HashMapMatchers<K, V> {
+    /// The map the assertion is on, which decides the keys it can be looked up with
+    type Map: ?Sized;
+
     fn to_be_empty(self) -> Self;
     fn to_have_length(self, expected: usize) -> Self;
     fn to_contain_key<Q>(self, key: &Q) -> Self
     where
         K: Borrow<Q>,
-        Q: Hash + Eq + Debug + ?Sized;
+        Q: Debug + ?Sized,
+        Self::Map: MapLookup<Q, V>;
     fn to_contain_entry<Q, R>(self, key: &Q, value: &R) -> Self
     where
         K: Borrow<Q>,
         V: Borrow<R>,
-        Q: Hash + Eq + Debug + ?Sized,
+        Q: Debug + ?Sized,
+        R: PartialEq + Debug + ?Sized,
+        Self::Map: MapLookup<Q, V>;
+    fn to_contain_keys<U>(self, keys: &[U]) -> Self
+    where
+        U: PartialEq<K> + Debug;
+    fn to_contain_value<R>(self, value: &R) -> Self
+    where
+        V: Borrow<R>,
         R: PartialEq + Debug + ?Sized;
-}
-
-/// Helper trait for HashMap-like types
-trait AsHashMap<K, V> {
-    fn is_map_empty(&self) -> bool;
-    fn map_length(&self) -> usize;
-    fn map_contains_key<Q>(&self, key: &Q) -> bool
+    /// Check that every expected entry is in the map, which may hold other entries too
+    fn to_contain_entries<I, Q, R>(self, expected: I) -> Self
     where
-        K: Borrow<Q>,
-        Q: Hash + Eq + ?Sized;
-    fn map_contains_entry<Q, R>(&self, key: &Q, value: &R) -> bool
+        I: IntoIterator<Item = (Q, R)>,
+        Q: Borrow<K>,
+        R: Borrow<V>,
+        V: PartialEq,
+        Self::Map: MapLookup<K, V>;
+    /// Check that the map holds exactly the expected entries, in any order
+    fn to_equal_map<I, Q, R>(self, expected: I) -> Self
+    where
+        I: IntoIterator<Item = (Q, R)>,
+        Q: Borrow<K>,
+        R: Borrow<V>,
+        K: Eq,
+        V: PartialEq,
+        Self::Map: MapLookup<K, V>;
+    fn to_have_key_matching<F>(self, predicate: F) -> Self
+    where
+        F: Fn(&K) -> bool;
+    /// Continue the chain on the value stored under `key`, failing when the key is absent
+    fn to_have_value_at<Q>(self, key: &Q) -> Assertion<V>
     where
         K: Borrow<Q>,
+        Q: Debug + ?Sized,
+        Self::Map: MapLookup<Q, V>;
+}
+
+/// Lookup of a map's value by a borrowed key, with the bounds of the map's own `get`
+pub trait MapLookup<Q: ?Sized, V> {
+    /// The value stored under `key`
+    fn lookup(&self, key: &Q) -> Option<&V>;
+
+    /// Whether `value` is stored under `key`
+    fn contains_entry<R>(&self, key: &Q, value: &R) -> bool
+    where
         V: Borrow<R>,
-        Q: Hash + Eq + ?Sized,
-        R: PartialEq + ?Sized;
+        R: PartialEq + ?Sized,
+    {
+        return self.lookup(key).is_some_and(|v| v.borrow() == value);
+    }
 }
 
-// Implementation for &HashMap<K, V>
-impl<K, V> AsHashMap<K, V> for &HashMap<K, V>
+// Lookups through a reference to a map, forwarding to the map itself
+impl<M, Q, V> MapLookup<Q, V> for &M
 where
-    K: Hash + Eq,
-    V: Clone,
+    M: MapLookup<Q, V> + ?Sized,
+    Q: ?Sized,
+{
+    fn lookup(&self, key: &Q) -> Option<&V> {
+        (**self).lookup(key)
+    }
+}
+
+// A HashMap, with any hasher, looks up keys that are `Hash + Eq`
+impl<K, V, S, Q> MapLookup<Q, V> for HashMap<K, V, S>
+where
+    K: Borrow<Q> + Hash + Eq,
+    S: BuildHasher,
+    Q: Hash + Eq + ?Sized,
+{
+    fn lookup(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+// A BTreeMap looks up keys that are `Ord`
+impl<K, V, Q> MapLookup<Q, V> for BTreeMap<K, V>
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
 {
+    fn lookup(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+/// Helper trait for map-like types
+///
+/// Only `entries` is required; the other methods fall back to scanning it, and the standard maps override them with
+/// their native `len` and `is_empty`.
+trait AsHashMap<K, V> {
+    fn entries(&self) -> Vec<(&K, &V)>;
+
     fn is_map_empty(&self) -> bool {
-        self.is_empty()
+        self.map_length() == 0
     }
 
     fn map_length(&self) -> usize {
-        self.len()
+        self.entries().len()
     }
 
-    fn map_contains_key<Q>(&self, key: &Q) -> bool
+    /// The keys of the map, for failure messages
+    fn describe_keys(&self) -> String
     where
-        K: Borrow<Q>,
-        Q: Hash + Eq + ?Sized,
+        K: Debug,
     {
-        self.contains_key(key)
+        return format!("keys present: {}", sorted_list(self.entries().into_iter().map(|(k, _)| k)));
     }
+}
 
-    fn map_contains_entry<Q, R>(&self, key: &Q, value: &R) -> bool
-    where
-        K: Borrow<Q>,
-        V: Borrow<R>,
-        Q: Hash + Eq + ?Sized,
-        R: PartialEq + ?Sized,
-    {
-        self.get(key).is_some_and(|v| v.borrow() == value)
+/// Render items as a list sorted by their `Debug` rendering, so that maps without an order still report stably
+///
+/// Renderings that are both numbers compare as numbers, so keys 2 and 10 are listed in that order.
+fn sorted_list<T: Debug>(items: impl IntoIterator<Item = T>) -> String {
+    let mut rendered: Vec<String> = items.into_iter().map(|item| format!("{:?}", item)).collect();
+    rendered.sort_by(|a, b| compare_rendered(a, b));
+
+    return format!("[{}]", rendered.join(", "));
+}
+
+// Implementation for references to any supported map, forwarding to the map itself
+impl<M, K, V> AsHashMap<K, V> for &M
+where
+    M: AsHashMap<K, V> + ?Sized,
+{
+    fn entries(&self) -> Vec<(&K, &V)> {
+        (**self).entries()
+    }
+
+    fn is_map_empty(&self) -> bool {
+        (**self).is_map_empty()
+    }
+
+    fn map_length(&self) -> usize {
+        (**self).map_length()
     }
 }
 
-// Implementation for HashMap<K, V>
-impl<K, V> AsHashMap<K, V> for HashMap<K, V>
+// Implementation for HashMap<K, V, S>, with any hasher
+impl<K, V, S> AsHashMap<K, V> for HashMap<K, V, S>
 where
     K: Hash + Eq,
-    V: Clone,
+    S: BuildHasher,
 {
+    fn entries(&self) -> Vec<(&K, &V)> {
+        self.iter().collect()
+    }
+
     fn is_map_empty(&self) -> bool {
         self.is_empty()
     }
@@ -82,33 +181,35 @@ where
     fn map_length(&self) -> usize {
         self.len()
     }
+}
 
-    fn map_contains_key<Q>(&self, key: &Q) -> bool
-    where
-        K: Borrow<Q>,
-        Q: Hash + Eq + ?Sized,
-    {
-        self.contains_key(key)
+// Implementation for BTreeMap<K, V>
+impl<K, V> AsHashMap<K, V> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    fn entries(&self) -> Vec<(&K, &V)> {
+        self.iter().collect()
     }
 
-    fn map_contains_entry<Q, R>(&self, key: &Q, value: &R) -> bool
-    where
-        K: Borrow<Q>,
-        V: Borrow<R>,
-        Q: Hash + Eq + ?Sized,
-        R: PartialEq + ?Sized,
-    {
-        self.get(key).is_some_and(|v| v.borrow() == value)
+    fn is_map_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn map_length(&self) -> usize {
+        self.len()
     }
 }
 
 // Single implementation for any type that implements AsHashMap
 impl<M, K, V> HashMapMatchers<K, V> for Assertion<M>
 where
-    K: Hash + Eq + Debug + Clone,
+    K: Debug + Clone,
     V: Debug + Clone,
     M: AsHashMap<K, V> + Debug + Clone,
 {
+    type Map = M;
+
     fn to_be_empty(self) -> Self {
         let result = self.value.is_map_empty();
         let sentence = AssertionSentence::new("be", "empty");
@@ -121,17 +222,23 @@ where
         let result = actual_length == expected;
         let sentence = AssertionSentence::new("have", format!("length {}", expected));
 
-        return self.add_step(sentence, result);
+        return self.add_step(sentence, result).with_received(format!("length {}", actual_length));
     }
 
     fn to_contain_key<Q>(self, key: &Q) -> Self
     where
         K: Borrow<Q>,
-        Q: Hash + Eq + Debug + ?Sized,
+        Q: Debug + ?Sized,
+        M: MapLookup<Q, V>,
     {
-        let result = self.value.map_contains_key(key);
+        let result = self.value.lookup(key).is_some();
         let sentence = AssertionSentence::new("contain", format!("key {:?}", key));
 
+        if !result {
+            let received = self.value.describe_keys();
+            return self.add_step(sentence, result).with_received(received);
+        }
+
         return self.add_step(sentence, result);
     }
 
@@ -139,20 +246,143 @@ where
     where
         K: Borrow<Q>,
         V: Borrow<R>,
-        Q: Hash + Eq + Debug + ?Sized,
+        Q: Debug + ?Sized,
         R: PartialEq + Debug + ?Sized,
+        M: MapLookup<Q, V>,
     {
-        let result = self.value.map_contains_entry(key, value);
+        let result = self.value.contains_entry(key, value);
         let sentence = AssertionSentence::new("contain", format!("entry ({:?}, {:?})", key, value));
 
+        // Only a failed positive comparison has differences worth showing
+        if !result && !self.negated {
+            let actual = self.value.lookup(key).map(|actual| (key, actual));
+            let comparison = Comparison::of_maps([(key, value)], actual);
+            return self.add_step(sentence, result).with_comparison(comparison);
+        }
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_contain_keys<U>(self, keys: &[U]) -> Self
+    where
+        U: PartialEq<K> + Debug,
+    {
+        let entries = self.value.entries();
+        let missing: Vec<&U> = keys.iter().filter(|key| !entries.iter().any(|(k, _)| *key == *k)).collect();
+        let result = missing.is_empty();
+        let sentence = AssertionSentence::new("contain", format!("keys {:?}", keys));
+
+        let received = if result { self.value.describe_keys() } else { format!("missing {:?}; {}", missing, self.value.describe_keys()) };
+
+        return self.add_step(sentence, result).with_received(received);
+    }
+
+    fn to_contain_value<R>(self, value: &R) -> Self
+    where
+        V: Borrow<R>,
+        R: PartialEq + Debug + ?Sized,
+    {
+        let entries = self.value.entries();
+        let result = entries.iter().any(|(_, v)| (*v).borrow() == value);
+        let sentence = AssertionSentence::new("contain", format!("value {:?}", value));
+        let received = format!("values present: {}", sorted_list(entries.iter().map(|(_, v)| v)));
+
+        return self.add_step(sentence, result).with_received(received);
+    }
+
+    fn to_contain_entries<I, Q, R>(self, expected: I) -> Self
+    where
+        I: IntoIterator<Item = (Q, R)>,
+        Q: Borrow<K>,
+        R: Borrow<V>,
+        V: PartialEq,
+        M: MapLookup<K, V>,
+    {
+        let expected: Vec<(Q, R)> = expected.into_iter().collect();
+        let result = expected.iter().all(|(key, value)| self.value.contains_entry(key.borrow(), value.borrow()));
+        let expected: Vec<(&K, &V)> = expected.iter().map(|(key, value)| (key.borrow(), value.borrow())).collect();
+        let sentence = AssertionSentence::new("contain", format!("entries {}", describe_entries(&expected)));
+
+        // Compare against the actual entries under the expected keys, as a sub-map check ignores the others
+        if !result && !self.negated {
+            let actual: Vec<(&K, &V)> = expected.iter().filter_map(|(key, _)| self.value.lookup(*key).map(|value| (*key, value))).collect();
+            let comparison = Comparison::of_maps(expected, actual);
+            return self.add_step(sentence, result).with_comparison(comparison);
+        }
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_equal_map<I, Q, R>(self, expected: I) -> Self
+    where
+        I: IntoIterator<Item = (Q, R)>,
+        Q: Borrow<K>,
+        R: Borrow<V>,
+        K: Eq,
+        V: PartialEq,
+        M: MapLookup<K, V>,
+    {
+        let expected: Vec<(Q, R)> = expected.into_iter().collect();
+        let expected: Vec<(&K, &V)> = expected.iter().map(|(key, value)| (key.borrow(), value.borrow())).collect();
+        let actual = self.value.entries();
+
+        let contains_expected = expected.iter().all(|(key, value)| self.value.contains_entry(*key, *value));
+        let no_extra_keys = actual.iter().all(|(key, _)| expected.iter().any(|(expected_key, _)| expected_key == key));
+        let result = contains_expected && no_extra_keys;
+        let sentence = AssertionSentence::new("be", format!("equal to map {}", describe_entries(&expected)));
+
+        // Only a failed positive comparison has differences worth showing
+        if !result && !self.negated {
+            let comparison = Comparison::of_maps(expected, actual);
+            return self.add_step(sentence, result).with_comparison(comparison);
+        }
+
         return self.add_step(sentence, result);
     }
+
+    fn to_have_key_matching<F>(self, predicate: F) -> Self
+    where
+        F: Fn(&K) -> bool,
+    {
+        let matching: Vec<&K> = self.value.entries().into_iter().map(|(key, _)| key).filter(|key| predicate(key)).collect();
+        let result = !matching.is_empty();
+        let sentence = AssertionSentence::new("have", "a key matching the predicate");
+
+        // A failure is either no key matching, or a key matching under negation
+        let received = if result { format!("matching keys: {}", sorted_list(matching)) } else { self.value.describe_keys() };
+
+        return self.add_step(sentence, result).with_received(received);
+    }
+
+    fn to_have_value_at<Q>(self, key: &Q) -> Assertion<V>
+    where
+        K: Borrow<Q>,
+        Q: Debug + ?Sized,
+        M: MapLookup<Q, V>,
+    {
+        let inner = self.value.lookup(key).cloned();
+        let presence = AssertionSentence::new("contain", format!("key {:?}", key));
+        let outer = AssertionSentence::new("have", format!("key {:?} whose value", key));
+        let received = self.value.describe_keys();
+        let missing = format!("{} has no key {:?}, so there is no value to check", self.subject_name(), key);
+
+        return self.continue_with_inner(inner, presence, outer, Some(received), missing);
+    }
+}
+
+/// Render entries as `{key: value, ..}`, sorted by key
+fn describe_entries<K: Debug, V: Debug>(entries: &[(&K, &V)]) -> String {
+    let mut rendered: Vec<(String, String)> = entries.iter().map(|(key, value)| (format!("{:?}", key), format!("{:?}", value))).collect();
+    rendered.sort_by(|(a, _), (b, _)| compare_rendered(a, b));
+    let rendered: Vec<String> = rendered.into_iter().map(|(key, value)| format!("{}: {}", key, value)).collect();
+
+    return format!("{{{}}}", rendered.join(", "));
 }
 
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
 
     #[test]
     fn test_hashmap_to_be_empty() {
@@ -275,4 +505,263 @@ mod tests {
         let _assertion = expect!(&map).to_contain_entry(&1, &3);
         std::hint::black_box(_assertion);
     }
+
+    #[test]
+    fn test_failed_entry_captures_comparison() {
+        use crate::backend::Comparison;
+
+        let map: HashMap<&str, i32> = [("a", 1)].iter().cloned().collect();
+
+        let mut result = expect!(&map).to_contain_entry(&"a", &2);
+        result.mark_as_intermediate();
+        assert_eq!(
+            result.steps[0].comparison,
+            Some(Comparison::Map { expected: vec![("\"a\"".into(), "2".into())], actual: vec![("\"a\"".into(), "1".into())] })
+        );
+
+        let mut result = expect!(&map).to_contain_entry(&"b", &2);
+        result.mark_as_intermediate();
+        assert_eq!(result.steps[0].comparison, Some(Comparison::Map { expected: vec![("\"b\"".into(), "2".into())], actual: vec![] }));
+    }
+
+    #[test]
+    fn test_btreemap() {
+        use std::collections::BTreeMap;
+
+        crate::Reporter::disable_deduplication();
+
+        let scores: BTreeMap<String, u32> = [("ann".to_string(), 3), ("bob".to_string(), 5)].into_iter().collect();
+
+        expect!(&scores).to_contain_key("ann").and().to_contain_entry("bob", &5).and().to_have_length(2);
+        expect!(scores.clone()).not().to_contain_key("eve");
+        expect!(BTreeMap::<i32, i32>::new()).to_be_empty();
+    }
+
+    #[test]
+    fn test_map_with_custom_hasher() {
+        use std::hash::BuildHasherDefault;
+
+        crate::Reporter::disable_deduplication();
+
+        let mut config: HashMap<&str, &str, BuildHasherDefault<std::collections::hash_map::DefaultHasher>> = HashMap::default();
+        config.insert("mode", "fast");
+
+        expect!(&config).to_contain_entry("mode", "fast");
+        expect!(config.clone()).to_contain_key("mode").and().not().to_contain_key("level");
+    }
+
+    /// A key that can be hashed but not ordered
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Key(&'static str);
+
+    #[test]
+    fn test_hashmap_with_key_that_is_not_ord() {
+        crate::Reporter::disable_deduplication();
+
+        let map: HashMap<Key, u32> = [(Key("a"), 1), (Key("b"), 2)].into_iter().collect();
+
+        expect!(&map).to_contain_key(&Key("a")).and().to_contain_entry(&Key("b"), &2).and().not().to_contain_key(&Key("c"));
+        expect!(&map).to_contain_entries([(Key("a"), 1)]).and().to_equal_map([(Key("a"), 1), (Key("b"), 2)]);
+        expect!(&map).to_have_value_at(&Key("b")).to_equal(2);
+    }
+
+    fn scores() -> HashMap<String, u32> {
+        return [("ann".to_string(), 3), ("bob".to_string(), 5), ("cid".to_string(), 8)].into_iter().collect();
+    }
+
+    #[test]
+    fn test_missing_key_lists_sorted_keys() {
+        let mut result = expect!(scores()).to_contain_key("eve");
+        result.mark_as_intermediate();
+        assert_eq!(result.steps[0].received.as_deref(), Some("keys present: [\"ann\", \"bob\", \"cid\"]"));
+
+        let numbered: HashMap<i32, &str> = [(10, "ten"), (2, "two"), (-1, "minus one")].into_iter().collect();
+        let mut result = expect!(numbered).to_contain_key(&3);
+        result.mark_as_intermediate();
+        assert_eq!(result.steps[0].received.as_deref(), Some("keys present: [-1, 2, 10]"));
+    }
+
+    #[test]
+    fn test_to_contain_keys() {
+        crate::Reporter::disable_deduplication();
+
+        let scores = scores();
+
+        expect!(&scores).to_contain_keys(&["ann", "cid"]);
+        expect!(&scores).not().to_contain_keys(&["ann", "eve"]);
+
+        let mut result = expect!(&scores).to_contain_keys(&["eve", "bob", "zed"]);
+        result.mark_as_intermediate();
+        assert_eq!(result.steps[0].sentence.format(), "contain keys [\"eve\", \"bob\", \"zed\"]");
+        assert_eq!(result.steps[0].received.as_deref(), Some("missing [\"eve\", \"zed\"]; keys present: [\"ann\", \"bob\", \"cid\"]"));
+    }
+
+    #[test]
+    fn test_to_contain_value() {
+        crate::Reporter::disable_deduplication();
+
+        let scores = scores();
+
+        expect!(&scores).to_contain_value(&5);
+        expect!(&scores).not().to_contain_value(&4);
+    }
+
+    #[test]
+    #[should_panic(expected = "contain value 4\n  expected: contain value 4\n  received: values present: [3, 5, 8]")]
+    fn test_missing_value_fails() {
+        let _assertion = expect!(scores()).to_contain_value(&4);
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_to_contain_entries() {
+        crate::Reporter::disable_deduplication();
+
+        let scores = scores();
+        let subset: HashMap<String, u32> = [("ann".to_string(), 3), ("cid".to_string(), 8)].into_iter().collect();
+        let superset: HashMap<String, u32> = [("ann".to_string(), 3), ("dan".to_string(), 1)].into_iter().collect();
+
+        expect!(&scores).to_contain_entries(&subset);
+        expect!(&scores).to_contain_entries([("bob".to_string(), 5)]);
+        expect!(&scores).not().to_contain_entries(&superset);
+        expect!(&scores).to_contain_entries(Vec::<(String, u32)>::new());
+    }
+
+    #[test]
+    fn test_failed_entries_diff_expected_keys_only() {
+        use crate::backend::Comparison;
+
+        let expected: HashMap<String, u32> = [("bob".to_string(), 6), ("dan".to_string(), 1)].into_iter().collect();
+
+        let mut result = expect!(scores()).to_contain_entries(&expected);
+        result.mark_as_intermediate();
+        assert_eq!(result.steps[0].sentence.format(), "contain entries {\"bob\": 6, \"dan\": 1}");
+
+        let Some(Comparison::Map { mut expected, actual }) = result.steps[0].comparison.clone() else {
+            panic!("expected a map comparison");
+        };
+        expected.sort();
+        assert_eq!(expected, vec![("\"bob\"".to_string(), "6".to_string()), ("\"dan\"".to_string(), "1".to_string())]);
+        assert_eq!(actual, vec![("\"bob\"".to_string(), "5".to_string())]);
+    }
+
+    #[test]
+    fn test_to_equal_map() {
+        crate::Reporter::disable_deduplication();
+
+        let scores = scores();
+        let same: BTreeMap<String, u32> = scores.clone().into_iter().collect();
+
+        expect!(&scores).to_equal_map(&same);
+        expect!(&scores).to_equal_map([("cid".to_string(), 8), ("ann".to_string(), 3), ("bob".to_string(), 5)]);
+        expect!(&scores).not().to_equal_map([("ann".to_string(), 3), ("bob".to_string(), 5)]);
+        expect!(&scores).not().to_equal_map([("ann".to_string(), 3), ("bob".to_string(), 5), ("cid".to_string(), 9)]);
+    }
+
+    #[test]
+    fn test_failed_equal_map_diffs_all_keys() {
+        use crate::backend::Comparison;
+
+        let expected: BTreeMap<String, u32> =
+            [("ann".to_string(), 3), ("bob".to_string(), 6), ("dan".to_string(), 1)].into_iter().collect();
+
+        let mut result = expect!(scores()).to_equal_map(&expected);
+        result.mark_as_intermediate();
+        assert_eq!(result.steps[0].sentence.format(), "be equal to map {\"ann\": 3, \"bob\": 6, \"dan\": 1}");
+
+        let Some(Comparison::Map { expected, mut actual }) = result.steps[0].comparison.clone() else {
+            panic!("expected a map comparison");
+        };
+        actual.sort();
+        assert_eq!(expected.len(), 3);
+        assert_eq!(
+            actual,
+            vec![("\"ann\"".into(), "3".into()), ("\"bob\"".into(), "5".into()), ("\"cid\"".into(), "8".into())] as Vec<(String, String)>
+        );
+    }
+
+    #[test]
+    fn test_to_have_key_matching() {
+        crate::Reporter::disable_deduplication();
+
+        let scores = scores();
+
+        expect!(&scores).to_have_key_matching(|key| key.starts_with('b'));
+        expect!(&scores).not().to_have_key_matching(|key| key.len() > 3);
+
+        let mut result = expect!(&scores).not().to_have_key_matching(|key| key.ends_with('n'));
+        result.mark_as_intermediate();
+        assert_eq!(result.steps[0].received.as_deref(), Some("matching keys: [\"ann\"]"));
+    }
+
+    #[test]
+    #[should_panic(expected = "have a key matching the predicate\n  expected: have a key matching the predicate\n  received: keys present")]
+    fn test_no_key_matching_fails() {
+        let _assertion = expect!(scores()).to_have_key_matching(|key| key.is_empty());
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_to_have_value_at() {
+        crate::Reporter::disable_deduplication();
+
+        let scores = scores();
+
+        expect!(&scores).to_have_value_at("bob").to_equal(5);
+        expect!(&scores).to_contain_key("ann").and().to_have_value_at("ann").to_be_less_than(5).and().to_be_odd();
+
+        let mut result = expect!(&scores).to_have_value_at("cid").to_be_greater_than(10);
+        result.mark_as_intermediate();
+        assert_eq!(result.steps.len(), 1);
+        assert_eq!(result.steps[0].sentence.format(), "have key \"cid\" whose value is greater than 10");
+        assert!(!result.calculate_chain_result());
+    }
+
+    #[test]
+    fn test_to_have_value_at_drops_restated_key_check() {
+        let scores = scores();
+
+        let mut result = expect!(&scores).to_have_length(3).and().to_contain_key("ann").and().to_have_value_at("ann").to_equal(3);
+        result.mark_as_intermediate();
+
+        assert_eq!(result.steps.len(), 2);
+        assert_eq!(result.steps[1].sentence.format(), "have key \"ann\" whose value is equal to 3");
+    }
+
+    #[test]
+    fn test_to_have_value_at_nested() {
+        crate::Reporter::disable_deduplication();
+
+        let settings: HashMap<&str, Option<u32>> = [("retries", Some(3)), ("timeout", None)].into_iter().collect();
+
+        let mut result = expect!(&settings).to_have_value_at("retries").some_value().to_equal(4);
+        result.mark_as_intermediate();
+        assert_eq!(result.steps[0].sentence.format(), "have key \"retries\" whose value is Some whose value is equal to 4");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "contain key \"eve\"\n  expected: contain key \"eve\"\n  received: keys present: [\"ann\", \"bob\", \"cid\"]"
+    )]
+    fn test_to_have_value_at_missing_key_fails() {
+        let scores = scores();
+        let _assertion = expect!(scores).to_have_value_at("eve").to_equal(1);
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_to_have_value_at_missing_key_reports_present_keys() {
+        let result = std::panic::catch_unwind(|| {
+            soft_assertions(|| {
+                let scores = scores();
+                expect!(&scores).to_have_value_at("eve").to_equal(1);
+            });
+        });
+
+        let payload = result.unwrap_err();
+        let message = crate::backend::matchers::panic::panic_message(payload.as_ref()).unwrap().to_string();
+        assert!(message.contains("1. scores contain key \"eve\"\n"));
+        assert!(message.contains("received: keys present: [\"ann\", \"bob\", \"cid\"]\n"));
+        assert!(message.ends_with("the scope then panicked: scores has no key \"eve\", so there is no value to check"));
+    }
 }
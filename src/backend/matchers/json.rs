@@ -0,0 +1,506 @@
+use crate::backend::Assertion;
+use crate::backend::assertions::comparison::Comparison;
+use crate::backend::assertions::sentence::AssertionSentence;
+use serde_json::{Number, Value};
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+/// Matchers for JSON documents, given as text or as `serde_json::Value`
+///
+/// Documents are compared semantically: key order and whitespace do not matter, and numbers are compared by value
+/// (`1` equals `1.0`). Failures show a diff of the differing values by path, e.g. `~ $.user.id: 1 → 2`.
+pub trait JsonMatchers {
+    /// Check that the value parses as JSON
+    fn to_be_valid_json(self) -> Self;
+
+    /// Check that the value is the same JSON document as the expected one
+    fn to_equal_json(self, expected: impl AsJson) -> Self;
+
+    /// Check that every value of the expected document is in the value at the same path
+    ///
+    /// Objects may have more keys than expected; arrays must have the same length, element by element.
+    fn to_contain_json_subset(self, expected: impl AsJson) -> Self;
+
+    /// Continue the chain on the value selected by a path such as `$.user.id`, `$.items[0]` or `$["odd key"]`
+    fn to_have_json_path(self, path: &str) -> Assertion<Value>;
+}
+
+/// Types that can be read as a JSON document: JSON text, or an already parsed `serde_json::Value`
+pub trait AsJson {
+    /// Parse the document, or describe why it is not valid JSON
+    fn as_json(&self) -> Result<Value, String>;
+}
+
+impl AsJson for str {
+    fn as_json(&self) -> Result<Value, String> {
+        serde_json::from_str(self).map_err(|err| err.to_string())
+    }
+}
+
+impl AsJson for String {
+    fn as_json(&self) -> Result<Value, String> {
+        self.as_str().as_json()
+    }
+}
+
+impl AsJson for Cow<'_, str> {
+    fn as_json(&self) -> Result<Value, String> {
+        self.as_ref().as_json()
+    }
+}
+
+impl AsJson for Box<str> {
+    fn as_json(&self) -> Result<Value, String> {
+        (**self).as_json()
+    }
+}
+
+impl AsJson for Value {
+    fn as_json(&self) -> Result<Value, String> {
+        Ok(self.clone())
+    }
+}
+
+impl<T: AsJson + ?Sized> AsJson for &T {
+    fn as_json(&self) -> Result<Value, String> {
+        (**self).as_json()
+    }
+}
+
+/// One step of a JSON path
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Parse a path such as `$.user.id`, `$.items[0]` or `$["odd key"]` into its segments
+fn parse_path(path: &str) -> Result<Vec<PathSegment>, String> {
+    let Some(rest) = path.strip_prefix('$') else {
+        return Err("a path starts with '$'".to_string());
+    };
+
+    // Positions are counted in `rest`, one char after the `$`, and reported from 1
+    let chars: Vec<char> = rest.chars().collect();
+    let mut segments = Vec::new();
+    let mut position = 0;
+
+    while position < chars.len() {
+        match chars[position] {
+            '.' => {
+                let start = position + 1;
+                let mut end = start;
+                while end < chars.len() && chars[end] != '.' && chars[end] != '[' {
+                    end += 1;
+                }
+                if end == start {
+                    return Err(format!("empty key at char {}", position + 2));
+                }
+                segments.push(PathSegment::Key(chars[start..end].iter().collect()));
+                position = end;
+            }
+            '[' => {
+                let Some(length) = chars[position..].iter().position(|c| *c == ']') else {
+                    return Err(format!("unclosed '[' at char {}", position + 2));
+                };
+                let inner: String = chars[position + 1..position + length].iter().collect();
+                let quoted = ['"', '\''].iter().find_map(|quote| inner.strip_prefix(*quote)?.strip_suffix(*quote));
+                let segment = match quoted {
+                    Some(key) => PathSegment::Key(key.to_string()),
+                    None => match inner.parse::<usize>() {
+                        Ok(index) => PathSegment::Index(index),
+                        Err(_) => return Err(format!("invalid index [{}] at char {}", inner, position + 2)),
+                    },
+                };
+                segments.push(segment);
+                position += length + 1;
+            }
+            other => return Err(format!("unexpected '{}' at char {}", other, position + 2)),
+        }
+    }
+
+    return Ok(segments);
+}
+
+/// Render the path of a key under `parent`, quoting keys that are not plain identifiers
+fn child_path(parent: &str, key: &str) -> String {
+    let mut chars = key.chars();
+    let plain = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_') && chars.all(|c| c.is_alphanumeric() || c == '_');
+
+    return if plain { format!("{}.{}", parent, key) } else { format!("{}[{:?}]", parent, key) };
+}
+
+/// Name the kind of a JSON value, for failures that reach the wrong kind of value
+fn kind(value: &Value) -> &'static str {
+    return match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    };
+}
+
+/// Compare two numbers by value, so `1` equals `1.0`; integers are compared exactly before falling back to floats
+fn numbers_equal(expected: &Number, actual: &Number) -> bool {
+    if let (Some(expected), Some(actual)) = (expected.as_i64(), actual.as_i64()) {
+        return expected == actual;
+    }
+    if let (Some(expected), Some(actual)) = (expected.as_u64(), actual.as_u64()) {
+        return expected == actual;
+    }
+
+    return expected.as_f64() == actual.as_f64();
+}
+
+/// Select the value at a path, or describe where the path stops matching the document
+fn select<'a>(document: &'a Value, segments: &[PathSegment]) -> Result<&'a Value, String> {
+    let mut current = document;
+    let mut path = "$".to_string();
+
+    for segment in segments {
+        current = match (segment, current) {
+            (PathSegment::Key(key), Value::Object(map)) => match map.get(key) {
+                Some(value) => value,
+                None => return Err(format!("{} has no key {:?}", path, key)),
+            },
+            (PathSegment::Index(index), Value::Array(items)) => match items.get(*index) {
+                Some(value) => value,
+                None => return Err(format!("{} has {} elements", path, items.len())),
+            },
+            (PathSegment::Key(_), other) => return Err(format!("{} is {}, not an object", path, kind(other))),
+            (PathSegment::Index(_), other) => return Err(format!("{} is {}, not an array", path, kind(other))),
+        };
+
+        path = match segment {
+            PathSegment::Key(key) => child_path(&path, key),
+            PathSegment::Index(index) => format!("{}[{}]", path, index),
+        };
+    }
+
+    return Ok(current);
+}
+
+/// The differing values of two documents, by path, in the shape of a map comparison
+#[derive(Default)]
+struct JsonDiff {
+    expected: Vec<(String, String)>,
+    actual: Vec<(String, String)>,
+}
+
+impl JsonDiff {
+    /// Compare two documents; in a subset comparison, keys only found in the actual objects are ignored
+    fn of(expected: &Value, actual: &Value, subset: bool) -> Self {
+        let mut diff = Self::default();
+        diff.collect(expected, actual, "$", subset);
+        return diff;
+    }
+
+    fn collect(&mut self, expected: &Value, actual: &Value, path: &str, subset: bool) {
+        match (expected, actual) {
+            (Value::Object(expected), Value::Object(actual)) => {
+                for (key, expected_value) in expected {
+                    let path = child_path(path, key);
+                    match actual.get(key) {
+                        Some(actual_value) => self.collect(expected_value, actual_value, &path, subset),
+                        None => self.expected.push((path, expected_value.to_string())),
+                    }
+                }
+                if !subset {
+                    for (key, actual_value) in actual.iter().filter(|(key, _)| !expected.contains_key(*key)) {
+                        self.actual.push((child_path(path, key), actual_value.to_string()));
+                    }
+                }
+            }
+            (Value::Array(expected), Value::Array(actual)) => {
+                for index in 0..expected.len().max(actual.len()) {
+                    let path = format!("{}[{}]", path, index);
+                    match (expected.get(index), actual.get(index)) {
+                        (Some(expected_value), Some(actual_value)) => self.collect(expected_value, actual_value, &path, subset),
+                        (Some(expected_value), None) => self.expected.push((path, expected_value.to_string())),
+                        (None, Some(actual_value)) => self.actual.push((path, actual_value.to_string())),
+                        (None, None) => {}
+                    }
+                }
+            }
+            (Value::Number(expected), Value::Number(actual)) if numbers_equal(expected, actual) => {}
+            _ if expected != actual => {
+                self.expected.push((path.to_string(), expected.to_string()));
+                self.actual.push((path.to_string(), actual.to_string()));
+            }
+            _ => {}
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        return self.expected.is_empty() && self.actual.is_empty();
+    }
+
+    fn into_comparison(self) -> Comparison {
+        return Comparison::Map { expected: self.expected, actual: self.actual };
+    }
+}
+
+impl<V> Assertion<V>
+where
+    V: AsJson + Debug + Clone,
+{
+    /// Compare the value with an expected document, as a whole or as a subset
+    fn compare_json(self, expected: impl AsJson, sentence: AssertionSentence, subset: bool) -> Self {
+        let expected = match expected.as_json() {
+            Ok(expected) => expected,
+            Err(err) => {
+                // An invalid expected document always fails, whether or not the assertion is negated
+                let sentence = sentence.with_qualifier(format!("(invalid expected JSON: {})", err));
+                let result = self.negated;
+                return self.add_step(sentence, result);
+            }
+        };
+        let sentence = AssertionSentence::new(sentence.verb, format!("{} {}", sentence.object, expected));
+
+        let actual = match self.value.as_json() {
+            Ok(actual) => actual,
+            Err(err) => return self.add_step(sentence, false).with_received(format!("invalid JSON: {}", err)),
+        };
+
+        let diff = JsonDiff::of(&expected, &actual, subset);
+        let result = diff.is_empty();
+
+        // Only a failed positive comparison has differences worth showing
+        if !result && !self.negated {
+            return self.add_step(sentence, result).with_received(actual.to_string()).with_comparison(diff.into_comparison());
+        }
+
+        return self.add_step(sentence, result).with_received(actual.to_string());
+    }
+}
+
+impl<V> JsonMatchers for Assertion<V>
+where
+    V: AsJson + Debug + Clone,
+{
+    fn to_be_valid_json(self) -> Self {
+        let parsed = self.value.as_json();
+        let sentence = AssertionSentence::new("be", "valid JSON");
+
+        return match parsed {
+            Ok(_) => self.add_step(sentence, true),
+            Err(err) => self.add_step(sentence, false).with_received(format!("invalid JSON: {}", err)),
+        };
+    }
+
+    fn to_equal_json(self, expected: impl AsJson) -> Self {
+        return self.compare_json(expected, AssertionSentence::new("be", "equal to JSON"), false);
+    }
+
+    fn to_contain_json_subset(self, expected: impl AsJson) -> Self {
+        return self.compare_json(expected, AssertionSentence::new("contain", "JSON subset"), true);
+    }
+
+    fn to_have_json_path(self, path: &str) -> Assertion<Value> {
+        let presence = AssertionSentence::new("have", format!("JSON path {:?}", path));
+        let outer = AssertionSentence::new("have", format!("JSON path {:?} whose value", path));
+
+        let selected = match (self.value.as_json(), parse_path(path)) {
+            (Ok(document), Ok(segments)) => select(&document, &segments).cloned(),
+            (Err(err), _) => Err(format!("invalid JSON: {}", err)),
+            (_, Err(err)) => Err(format!("invalid JSON path: {}", err)),
+        };
+
+        let (inner, received) = match selected {
+            Ok(value) => (Some(value), None),
+            Err(reason) => (None, Some(reason)),
+        };
+        let missing = format!("{} has no value at JSON path {:?}, so there is no value to check", self.subject_name(), path);
+
+        return self.continue_with_inner(inner, presence, outer, received, missing);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PathSegment, parse_path};
+    use crate::backend::Comparison;
+    use crate::prelude::*;
+    use serde_json::json;
+
+    const USER: &str = r#"{"user": {"id": 7, "name": "Ann", "tags": ["admin", "ops"]}, "active": true}"#;
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(parse_path("$"), Ok(vec![]));
+        assert_eq!(
+            parse_path("$.user.tags[1]"),
+            Ok(vec![PathSegment::Key("user".into()), PathSegment::Key("tags".into()), PathSegment::Index(1)])
+        );
+        assert_eq!(parse_path("$[\"odd key\"]['x']"), Ok(vec![PathSegment::Key("odd key".into()), PathSegment::Key("x".into())]));
+        assert_eq!(parse_path("user.id"), Err("a path starts with '$'".to_string()));
+        assert_eq!(parse_path("$.user..id"), Err("empty key at char 7".to_string()));
+        assert_eq!(parse_path("$.items[first]"), Err("invalid index [first] at char 8".to_string()));
+        assert_eq!(parse_path("$.items[0"), Err("unclosed '[' at char 8".to_string()));
+    }
+
+    #[test]
+    fn test_to_be_valid_json() {
+        crate::Reporter::disable_deduplication();
+
+        expect!(USER).to_be_valid_json();
+        expect!("[1, 2").not().to_be_valid_json();
+        expect!(json!({"a": 1})).to_be_valid_json();
+    }
+
+    #[test]
+    #[should_panic(expected = "be valid JSON\n  expected: be valid JSON\n  received: invalid JSON: EOF while parsing a list")]
+    fn test_invalid_json_fails() {
+        let _assertion = expect!("[1, 2").to_be_valid_json();
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_to_equal_json() {
+        crate::Reporter::disable_deduplication();
+
+        let body = String::from(USER);
+
+        expect!(&body).to_equal_json(r#"{"active": true, "user": {"tags": ["admin", "ops"], "name": "Ann", "id": 7}}"#);
+        expect!(&body).to_equal_json(json!({"active": true, "user": {"id": 7, "name": "Ann", "tags": ["admin", "ops"]}}));
+        expect!(json!([1, 2])).to_equal_json("[1,2]");
+        expect!(&body).not().to_equal_json(r#"{"active": true}"#);
+        expect!(json!([1, 2])).not().to_equal_json("[2, 1]");
+    }
+
+    #[test]
+    fn test_failed_equal_json_diffs_by_path() {
+        let mut result = expect!(USER).to_equal_json(json!({"user": {"id": 8, "name": "Ann", "tags": ["admin"], "team": "core"}}));
+        result.mark_as_intermediate();
+
+        assert_eq!(result.steps[0].sentence.format(), r#"be equal to JSON {"user":{"id":8,"name":"Ann","tags":["admin"],"team":"core"}}"#);
+
+        let Some(Comparison::Map { mut expected, mut actual }) = result.steps[0].comparison.clone() else {
+            panic!("expected a map comparison");
+        };
+        expected.sort();
+        actual.sort();
+        assert_eq!(expected, vec![("$.user.id".to_string(), "8".to_string()), ("$.user.team".to_string(), "\"core\"".to_string())]);
+        assert_eq!(
+            actual,
+            vec![
+                ("$.active".to_string(), "true".to_string()),
+                ("$.user.id".to_string(), "7".to_string()),
+                ("$.user.tags[1]".to_string(), "\"ops\"".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_quotes_odd_keys() {
+        let mut result = expect!(json!({"odd key": 1})).to_equal_json(json!({"odd key": 2}));
+        result.mark_as_intermediate();
+
+        assert_eq!(
+            result.steps[0].comparison,
+            Some(Comparison::Map {
+                expected: vec![("$[\"odd key\"]".into(), "2".into())],
+                actual: vec![("$[\"odd key\"]".into(), "1".into())]
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "(invalid expected JSON: ")]
+    fn test_invalid_expected_json_fails() {
+        let _assertion = expect!(USER).not().to_equal_json("{oops}");
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "received: invalid JSON: ")]
+    fn test_equal_json_on_invalid_value_fails() {
+        let _assertion = expect!("not json").to_equal_json("{}");
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_numbers_compare_by_value() {
+        crate::Reporter::disable_deduplication();
+
+        expect!(json!({"a": 1})).to_equal_json(r#"{"a": 1.0}"#);
+        expect!(json!({"a": 1.0})).to_equal_json(json!({"a": 1}));
+        expect!(json!([-3, 2.5])).to_equal_json("[-3.0, 2.5]");
+        expect!(json!({"a": 1, "b": 2})).to_contain_json_subset(r#"{"a": 1.0}"#);
+        expect!(json!({"a": 1})).not().to_equal_json(r#"{"a": 1.5}"#);
+        expect!(json!(u64::MAX)).not().to_equal_json(json!(u64::MAX - 1));
+    }
+
+    #[test]
+    fn test_to_contain_json_subset() {
+        crate::Reporter::disable_deduplication();
+
+        expect!(USER).to_contain_json_subset(r#"{"user": {"name": "Ann"}}"#);
+        expect!(USER).to_contain_json_subset(json!({"active": true, "user": {"tags": ["admin", "ops"]}}));
+        expect!(USER).not().to_contain_json_subset(json!({"user": {"tags": ["admin"]}}));
+        expect!(USER).not().to_contain_json_subset(json!({"user": {"email": "ann@example.com"}}));
+    }
+
+    #[test]
+    fn test_failed_subset_diffs_expected_paths_only() {
+        let mut result = expect!(USER).to_contain_json_subset(json!({"user": {"name": "Bob", "email": "bob@example.com"}}));
+        result.mark_as_intermediate();
+
+        let Some(Comparison::Map { mut expected, actual }) = result.steps[0].comparison.clone() else {
+            panic!("expected a map comparison");
+        };
+        expected.sort();
+        assert_eq!(
+            expected,
+            vec![("$.user.email".to_string(), "\"bob@example.com\"".to_string()), ("$.user.name".to_string(), "\"Bob\"".to_string())]
+        );
+        assert_eq!(actual, vec![("$.user.name".to_string(), "\"Ann\"".to_string())]);
+    }
+
+    #[test]
+    fn test_to_have_json_path() {
+        crate::Reporter::disable_deduplication();
+
+        expect!(USER).to_have_json_path("$.user.id").to_equal(json!(7));
+        expect!(USER).to_have_json_path("$.user.tags[1]").to_equal_json(r#""ops""#);
+        expect!(USER).to_have_json_path("$.user").to_have_json_path("$.name").to_equal(json!("Ann"));
+        expect!(json!({"odd key": [null]})).to_have_json_path("$['odd key'][0]").to_equal(json!(null));
+    }
+
+    #[test]
+    fn test_to_have_json_path_reads_as_part_of_the_document() {
+        let body = USER;
+
+        let mut result = expect!(body).to_have_json_path("$.user.id").to_equal_json("8");
+        result.mark_as_intermediate();
+
+        assert_eq!(result.steps.len(), 1);
+        assert_eq!(result.steps[0].sentence.format(), "have JSON path \"$.user.id\" whose value is equal to JSON 8");
+        assert!(!result.calculate_chain_result());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "have JSON path \"$.user.email\"\n  expected: have JSON path \"$.user.email\"\n  received: $.user has no key \"email\""
+    )]
+    fn test_missing_json_path_fails() {
+        let _assertion = expect!(USER).to_have_json_path("$.user.email").to_equal(json!("ann@example.com"));
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "received: $.user.tags has 2 elements")]
+    fn test_json_path_out_of_bounds_fails() {
+        let _assertion = expect!(USER).to_have_json_path("$.user.tags[5]").to_equal(json!("x"));
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "received: $.user.name is a string, not an object")]
+    fn test_json_path_through_scalar_fails() {
+        let _assertion = expect!(USER).to_have_json_path("$.user.name.first").to_equal(json!("x"));
+        std::hint::black_box(_assertion);
+    }
+}
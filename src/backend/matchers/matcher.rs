@@ -0,0 +1,630 @@
+//! First-class matcher values that can be stored, passed around, nested and combined
+//!
+//! Unlike the extension-trait matchers, which record a step as soon as they are called, a `Matcher<T>` only
+//! describes a check. It is applied with `expect!(value).to_satisfy(matcher)`.
+
+use crate::backend::Assertion;
+use crate::backend::assertions::sentence::AssertionSentence;
+use std::fmt::Debug;
+
+/// A reusable check on values of type `T`
+pub trait Matcher<T: ?Sized> {
+    /// Check the value, explaining what was observed
+    fn matches(&self, actual: &T) -> MatchResult;
+
+    /// Describe what the matcher expects, e.g. "be greater than 3"
+    fn describe(&self) -> AssertionSentence;
+
+    /// Box the matcher, to combine matchers of different types in `all_of`, `any_of` or `none_of`
+    fn boxed(self) -> Box<dyn Matcher<T>>
+    where
+        Self: Sized + 'static,
+    {
+        return Box::new(self);
+    }
+}
+
+/// Outcome of applying a matcher to a value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchResult {
+    /// Whether the value satisfied the matcher
+    pub matched: bool,
+    /// What the matcher observed, e.g. `Some(0)` or which elements did not match
+    pub explanation: String,
+}
+
+impl MatchResult {
+    /// Creates a new match result
+    pub fn new(matched: bool, explanation: impl Into<String>) -> Self {
+        return Self { matched, explanation: explanation.into() };
+    }
+}
+
+impl<T: ?Sized> Matcher<T> for Box<dyn Matcher<T>> {
+    fn matches(&self, actual: &T) -> MatchResult {
+        return self.as_ref().matches(actual);
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        return self.as_ref().describe();
+    }
+}
+
+/// Describe a nested matcher in the third person, as in "whose value is greater than 3"
+fn describe_nested<T: ?Sized>(matcher: &impl Matcher<T>, subject: &str) -> String {
+    return matcher.describe().format_with_conjugation(subject);
+}
+
+/// Entry point applying a `Matcher` to the value under test
+pub trait SatisfyMatchers<T> {
+    /// Check that the value satisfies the matcher
+    fn to_satisfy<M: Matcher<T>>(self, matcher: M) -> Self;
+
+    /// Check that the value satisfies a one-off predicate, described by a phrase starting with a verb
+    /// such as "be a valid ISBN"
+    fn to_satisfy_fn<F: Fn(&T) -> bool>(self, predicate: F, description: &str) -> Self;
+}
+
+impl<T: Clone + Debug> SatisfyMatchers<T> for Assertion<T> {
+    fn to_satisfy<M: Matcher<T>>(self, matcher: M) -> Self {
+        let result = matcher.matches(&self.value);
+
+        return self.add_step(matcher.describe(), result.matched).with_received(result.explanation);
+    }
+
+    fn to_satisfy_fn<F: Fn(&T) -> bool>(self, predicate: F, description: &str) -> Self {
+        return self.to_satisfy(Predicate { predicate, description: description.to_string() });
+    }
+}
+
+/// Matches values equal to the expected one
+pub struct Equal<E>(E);
+
+/// Matches values different from the expected one
+pub struct NotEqual<E>(E);
+
+/// Matches values ordered in a given way relative to a bound
+pub struct Compare<E> {
+    bound: E,
+    relation: Relation,
+}
+
+#[derive(Clone, Copy)]
+enum Relation {
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+}
+
+/// Matches strings containing a substring and collections containing an element
+pub struct Contains<E>(E);
+
+/// Matches `Some` values whose content satisfies the inner matcher
+pub struct SomeMatcher<M>(M);
+
+/// Matches `None`
+pub struct NoneMatcher;
+
+/// Matches `Ok` values whose content satisfies the inner matcher
+pub struct OkMatcher<M>(M);
+
+/// Matches `Err` values whose error satisfies the inner matcher
+pub struct ErrMatcher<M>(M);
+
+/// Matches collections whose every element satisfies the inner matcher
+pub struct Each<M>(M);
+
+/// Matches values that do not satisfy the inner matcher
+pub struct Not<M>(M);
+
+/// Matches values for which a closure returns true
+pub struct Predicate<F> {
+    predicate: F,
+    description: String,
+}
+
+/// Matches values satisfying every, any or none of the inner matchers
+pub struct Combination<M> {
+    matchers: Vec<M>,
+    kind: CombinationKind,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CombinationKind {
+    All,
+    Any,
+    None,
+}
+
+/// Matches values equal to `expected`
+pub fn eq<E>(expected: E) -> Equal<E> {
+    return Equal(expected);
+}
+
+/// Matches values different from `expected`
+pub fn ne<E>(expected: E) -> NotEqual<E> {
+    return NotEqual(expected);
+}
+
+/// Matches values greater than `bound`
+pub fn gt<E>(bound: E) -> Compare<E> {
+    return Compare { bound, relation: Relation::Greater };
+}
+
+/// Matches values greater than or equal to `bound`
+pub fn ge<E>(bound: E) -> Compare<E> {
+    return Compare { bound, relation: Relation::GreaterOrEqual };
+}
+
+/// Matches values less than `bound`
+pub fn lt<E>(bound: E) -> Compare<E> {
+    return Compare { bound, relation: Relation::Less };
+}
+
+/// Matches values less than or equal to `bound`
+pub fn le<E>(bound: E) -> Compare<E> {
+    return Compare { bound, relation: Relation::LessOrEqual };
+}
+
+/// Matches strings containing the substring, or collections containing the element
+pub fn contains<E>(expected: E) -> Contains<E> {
+    return Contains(expected);
+}
+
+/// Matches `Some` values whose content satisfies `matcher`
+pub fn some<M>(matcher: M) -> SomeMatcher<M> {
+    return SomeMatcher(matcher);
+}
+
+/// Matches `None`
+pub fn none() -> NoneMatcher {
+    return NoneMatcher;
+}
+
+/// Matches `Ok` values whose content satisfies `matcher`
+pub fn ok<M>(matcher: M) -> OkMatcher<M> {
+    return OkMatcher(matcher);
+}
+
+/// Matches `Err` values whose error satisfies `matcher`
+pub fn err<M>(matcher: M) -> ErrMatcher<M> {
+    return ErrMatcher(matcher);
+}
+
+/// Matches collections whose every element satisfies `matcher`
+pub fn each<M>(matcher: M) -> Each<M> {
+    return Each(matcher);
+}
+
+/// Matches values for which `predicate` returns true, described by a phrase starting with a verb
+/// such as "be a valid ISBN"
+pub fn predicate<F>(predicate: F, description: &str) -> Predicate<F> {
+    return Predicate { predicate, description: description.to_string() };
+}
+
+/// Matches values that do not satisfy `matcher`
+pub fn not<M>(matcher: M) -> Not<M> {
+    return Not(matcher);
+}
+
+/// Matches values satisfying every matcher
+pub fn all_of<M>(matchers: impl IntoIterator<Item = M>) -> Combination<M> {
+    return Combination { matchers: matchers.into_iter().collect(), kind: CombinationKind::All };
+}
+
+/// Matches values satisfying at least one matcher
+pub fn any_of<M>(matchers: impl IntoIterator<Item = M>) -> Combination<M> {
+    return Combination { matchers: matchers.into_iter().collect(), kind: CombinationKind::Any };
+}
+
+/// Matches values satisfying none of the matchers
+pub fn none_of<M>(matchers: impl IntoIterator<Item = M>) -> Combination<M> {
+    return Combination { matchers: matchers.into_iter().collect(), kind: CombinationKind::None };
+}
+
+impl<T, E> Matcher<T> for Equal<E>
+where
+    T: PartialEq<E> + Debug,
+    E: Debug,
+{
+    fn matches(&self, actual: &T) -> MatchResult {
+        return MatchResult::new(*actual == self.0, format!("{:?}", actual));
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        return AssertionSentence::new("be", format!("equal to {:?}", self.0));
+    }
+}
+
+impl<T, E> Matcher<T> for NotEqual<E>
+where
+    T: PartialEq<E> + Debug,
+    E: Debug,
+{
+    fn matches(&self, actual: &T) -> MatchResult {
+        return MatchResult::new(*actual != self.0, format!("{:?}", actual));
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        return AssertionSentence::new("be", format!("different from {:?}", self.0));
+    }
+}
+
+impl<T, E> Matcher<T> for Compare<E>
+where
+    T: PartialOrd<E> + Debug,
+    E: Debug,
+{
+    fn matches(&self, actual: &T) -> MatchResult {
+        let matched = match self.relation {
+            Relation::Greater => *actual > self.bound,
+            Relation::GreaterOrEqual => *actual >= self.bound,
+            Relation::Less => *actual < self.bound,
+            Relation::LessOrEqual => *actual <= self.bound,
+        };
+
+        return MatchResult::new(matched, format!("{:?}", actual));
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        let relation = match self.relation {
+            Relation::Greater => "greater than",
+            Relation::GreaterOrEqual => "greater than or equal to",
+            Relation::Less => "less than",
+            Relation::LessOrEqual => "less than or equal to",
+        };
+
+        return AssertionSentence::new("be", format!("{} {:?}", relation, self.bound));
+    }
+}
+
+/// Helper trait for values that can contain something, like a substring or an element
+trait Containing<E> {
+    fn contains_expected(&self, expected: &E) -> bool;
+}
+
+impl<E: AsRef<str>> Containing<E> for String {
+    fn contains_expected(&self, expected: &E) -> bool {
+        return self.contains(expected.as_ref());
+    }
+}
+
+impl<E: AsRef<str>> Containing<E> for &str {
+    fn contains_expected(&self, expected: &E) -> bool {
+        return self.contains(expected.as_ref());
+    }
+}
+
+impl<T: PartialEq<E>, E> Containing<E> for Vec<T> {
+    fn contains_expected(&self, expected: &E) -> bool {
+        return self.iter().any(|item| *item == *expected);
+    }
+}
+
+impl<T: PartialEq<E>, E> Containing<E> for &[T] {
+    fn contains_expected(&self, expected: &E) -> bool {
+        return self.iter().any(|item| *item == *expected);
+    }
+}
+
+impl<T, E> Matcher<T> for Contains<E>
+where
+    T: Containing<E> + Debug,
+    E: Debug,
+{
+    fn matches(&self, actual: &T) -> MatchResult {
+        return MatchResult::new(actual.contains_expected(&self.0), format!("{:?}", actual));
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        return AssertionSentence::new("contain", format!("{:?}", self.0));
+    }
+}
+
+impl<T, M: Matcher<T>> Matcher<Option<T>> for SomeMatcher<M> {
+    fn matches(&self, actual: &Option<T>) -> MatchResult {
+        return match actual {
+            Some(value) => {
+                let inner = self.0.matches(value);
+                MatchResult::new(inner.matched, format!("Some({})", inner.explanation))
+            }
+            None => MatchResult::new(false, "None"),
+        };
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        return AssertionSentence::new("be", format!("Some whose value {}", describe_nested(&self.0, "value")));
+    }
+}
+
+impl<T: Debug> Matcher<Option<T>> for NoneMatcher {
+    fn matches(&self, actual: &Option<T>) -> MatchResult {
+        return MatchResult::new(actual.is_none(), format!("{:?}", actual));
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        return AssertionSentence::new("be", "None");
+    }
+}
+
+impl<T, E: Debug, M: Matcher<T>> Matcher<Result<T, E>> for OkMatcher<M> {
+    fn matches(&self, actual: &Result<T, E>) -> MatchResult {
+        return match actual {
+            Ok(value) => {
+                let inner = self.0.matches(value);
+                MatchResult::new(inner.matched, format!("Ok({})", inner.explanation))
+            }
+            Err(error) => MatchResult::new(false, format!("Err({:?})", error)),
+        };
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        return AssertionSentence::new("be", format!("Ok whose value {}", describe_nested(&self.0, "value")));
+    }
+}
+
+impl<T: Debug, E, M: Matcher<E>> Matcher<Result<T, E>> for ErrMatcher<M> {
+    fn matches(&self, actual: &Result<T, E>) -> MatchResult {
+        return match actual {
+            Ok(value) => MatchResult::new(false, format!("Ok({:?})", value)),
+            Err(error) => {
+                let inner = self.0.matches(error);
+                MatchResult::new(inner.matched, format!("Err({})", inner.explanation))
+            }
+        };
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        return AssertionSentence::new("be", format!("Err whose error {}", describe_nested(&self.0, "error")));
+    }
+}
+
+impl<M> Each<M> {
+    /// Check every element, listing the ones that did not match
+    fn match_elements<'a, T: 'a>(&self, elements: impl IntoIterator<Item = &'a T>) -> MatchResult
+    where
+        M: Matcher<T>,
+    {
+        let mut count = 0;
+        let mut mismatches = Vec::new();
+        for (index, element) in elements.into_iter().enumerate() {
+            let result = self.0.matches(element);
+            if !result.matched {
+                mismatches.push(format!("[{}] {}", index, result.explanation));
+            }
+            count += 1;
+        }
+
+        if mismatches.is_empty() {
+            return MatchResult::new(true, format!("{} matching elements", count));
+        }
+
+        return MatchResult::new(false, format!("mismatched elements {}", mismatches.join(", ")));
+    }
+
+    fn describe_elements<T>(&self) -> AssertionSentence
+    where
+        M: Matcher<T>,
+    {
+        return AssertionSentence::new("have", format!("only elements that {}", describe_nested(&self.0, "elements")));
+    }
+}
+
+impl<T, M: Matcher<T>> Matcher<Vec<T>> for Each<M> {
+    fn matches(&self, actual: &Vec<T>) -> MatchResult {
+        return self.match_elements(actual);
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        return self.describe_elements();
+    }
+}
+
+impl<T, M: Matcher<T>> Matcher<&[T]> for Each<M> {
+    fn matches(&self, actual: &&[T]) -> MatchResult {
+        return self.match_elements(*actual);
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        return self.describe_elements();
+    }
+}
+
+impl<T, M: Matcher<T>, const N: usize> Matcher<[T; N]> for Each<M> {
+    fn matches(&self, actual: &[T; N]) -> MatchResult {
+        return self.match_elements(actual);
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        return self.describe_elements();
+    }
+}
+
+impl<T, F> Matcher<T> for Predicate<F>
+where
+    T: Debug,
+    F: Fn(&T) -> bool,
+{
+    fn matches(&self, actual: &T) -> MatchResult {
+        return MatchResult::new((self.predicate)(actual), format!("{:?}", actual));
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        // The leading verb is kept apart so the sentence can be conjugated and negated like built-in ones
+        let description = self.description.trim();
+        return match description.split_once(char::is_whitespace) {
+            Some((verb, object)) => AssertionSentence::new(verb, object.trim_start()),
+            None => AssertionSentence::new("satisfy", description),
+        };
+    }
+}
+
+impl<T: ?Sized, M: Matcher<T>> Matcher<T> for Not<M> {
+    fn matches(&self, actual: &T) -> MatchResult {
+        let inner = self.0.matches(actual);
+        return MatchResult::new(!inner.matched, inner.explanation);
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        let sentence = self.0.describe();
+        let negated = !sentence.negated;
+        return sentence.with_negation(negated);
+    }
+}
+
+impl<T: ?Sized, M: Matcher<T>> Matcher<T> for Combination<M> {
+    fn matches(&self, actual: &T) -> MatchResult {
+        let results: Vec<MatchResult> = self.matchers.iter().map(|matcher| matcher.matches(actual)).collect();
+        let matched_count = results.iter().filter(|result| result.matched).count();
+
+        let matched = match self.kind {
+            CombinationKind::All => matched_count == results.len(),
+            CombinationKind::Any => matched_count > 0,
+            CombinationKind::None => matched_count == 0,
+        };
+
+        // Explain with the result that decided the outcome, falling back to the first one
+        let deciding = match self.kind {
+            CombinationKind::All => results.iter().find(|result| !result.matched),
+            CombinationKind::Any => results.iter().find(|result| result.matched),
+            CombinationKind::None => results.iter().find(|result| result.matched),
+        };
+        let explanation = deciding.or(results.first()).map(|result| result.explanation.clone()).unwrap_or_default();
+
+        return MatchResult::new(matched, explanation);
+    }
+
+    fn describe(&self) -> AssertionSentence {
+        let kind = match self.kind {
+            CombinationKind::All => "all of",
+            CombinationKind::Any => "any of",
+            CombinationKind::None => "none of",
+        };
+        let descriptions: Vec<String> = self.matchers.iter().map(|matcher| describe_nested(matcher, "value")).collect();
+
+        return AssertionSentence::new("match", format!("{} ({})", kind, descriptions.join(", ")));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_leaf_matchers() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        expect!(4).to_satisfy(eq(4));
+        expect!(4).to_satisfy(ne(5));
+        expect!(4).to_satisfy(gt(3));
+        expect!(4).to_satisfy(ge(4));
+        expect!(4).to_satisfy(lt(5));
+        expect!(4).to_satisfy(le(4));
+        expect!(4).not().to_satisfy(gt(4));
+        expect!("hello world").to_satisfy(contains("world"));
+        expect!(vec![1, 2, 3]).to_satisfy(contains(2));
+    }
+
+    #[test]
+    fn test_structural_matchers() {
+        crate::Reporter::disable_deduplication();
+
+        let ok_value: Result<i32, String> = Ok(3);
+        let err_value: Result<i32, String> = Err("boom".to_string());
+
+        expect!(Some(3)).to_satisfy(some(eq(3)));
+        expect!(None::<i32>).to_satisfy(none());
+        expect!(ok_value).to_satisfy(ok(gt(0)));
+        expect!(err_value).to_satisfy(err(contains("boom")));
+        expect!(vec![Some(1), Some(2)]).to_satisfy(each(some(gt(0))));
+        expect!(vec![Some(1), None]).not().to_satisfy(each(some(gt(0))));
+    }
+
+    #[test]
+    fn test_combinators() {
+        crate::Reporter::disable_deduplication();
+
+        expect!(5).to_satisfy(all_of([gt(0), lt(10)]));
+        expect!(5).to_satisfy(any_of([lt(0), gt(4)]));
+        expect!(5).to_satisfy(none_of([lt(0), gt(10)]));
+        expect!(5).to_satisfy(not(eq(4)));
+        expect!(5).to_satisfy(all_of([gt(0).boxed(), not(eq(4)).boxed()]));
+    }
+
+    #[test]
+    fn test_descriptions() {
+        assert_eq!(Matcher::<i32>::describe(&gt(3)).format(), "be greater than 3");
+        assert_eq!(Matcher::<Option<i32>>::describe(&some(gt(3))).format(), "be Some whose value is greater than 3");
+        assert_eq!(
+            Matcher::<Vec<Option<i32>>>::describe(&each(some(gt(0)))).format(),
+            "have only elements that are Some whose value is greater than 0"
+        );
+        assert_eq!(Matcher::<i32>::describe(&not(eq(4))).format(), "not be equal to 4");
+        assert_eq!(Matcher::<i32>::describe(&all_of([gt(0), lt(10)])).format(), "match all of (is greater than 0, is less than 10)");
+    }
+
+    #[test]
+    fn test_explanations() {
+        let result = each(some(gt(0))).matches(&vec![Some(1), None, Some(0)]);
+
+        assert!(!result.matched);
+        assert_eq!(result.explanation, "mismatched elements [1] None, [2] Some(0)");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "have only elements that are Some whose value is greater than 0\n  expected: have only elements that are Some whose value is greater than 0\n  received: mismatched elements [1] None"
+    )]
+    fn test_nested_failure_reports_elements() {
+        let _assertion = expect!(vec![Some(1), None]).to_satisfy(each(some(gt(0))));
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "not be equal to 4")]
+    fn test_negated_matcher_fails() {
+        let _assertion = expect!(4).to_satisfy(not(eq(4)));
+        std::hint::black_box(_assertion);
+    }
+
+    fn is_valid_isbn(isbn: &&str) -> bool {
+        let digits: Vec<u32> = isbn.chars().filter_map(|c| c.to_digit(10)).collect();
+        return digits.len() == 13 && digits.iter().enumerate().map(|(i, d)| if i % 2 == 0 { *d } else { d * 3 }).sum::<u32>() % 10 == 0;
+    }
+
+    #[test]
+    fn test_to_satisfy_fn() {
+        crate::Reporter::disable_deduplication();
+
+        expect!("978-0-306-40615-7").to_satisfy_fn(is_valid_isbn, "be a valid ISBN");
+        expect!("978-0-306-40615-8").not().to_satisfy_fn(is_valid_isbn, "be a valid ISBN");
+        expect!(12).to_satisfy_fn(|n| n % 3 == 0, "be divisible by 3").and().to_satisfy_fn(|n| n % 4 == 0, "be divisible by 4");
+        expect!(10).to_satisfy_fn(|n| n % 3 == 0, "be divisible by 3").or().to_be_even();
+        let divisible_by_3 = predicate(|n: &i32| n % 3 == 0, "be divisible by 3");
+        let positive = predicate(|n: &i32| *n > 0, "be positive");
+        expect!(9).to_satisfy(all_of([divisible_by_3.boxed(), positive.boxed()]));
+    }
+
+    #[test]
+    fn test_predicate_descriptions() {
+        assert_eq!(
+            Matcher::<i32>::describe(&predicate(|_: &i32| true, "be a valid ISBN")).format_with_conjugation("isbn"),
+            "is a valid ISBN"
+        );
+        assert_eq!(
+            Matcher::<i32>::describe(&not(predicate(|_: &i32| true, "have an even length"))).format_with_conjugation("code"),
+            "does not have an even length"
+        );
+        assert_eq!(Matcher::<i32>::describe(&predicate(|_: &i32| true, "sorted")).format(), "satisfy sorted");
+    }
+
+    #[test]
+    #[should_panic(expected = "not be a valid ISBN\n  expected: not be a valid ISBN\n  received: \"978-0-306-40615-7\"")]
+    fn test_negated_predicate_fails() {
+        let _assertion = expect!("978-0-306-40615-7").not().to_satisfy_fn(is_valid_isbn, "be a valid ISBN");
+        std::hint::black_box(_assertion);
+    }
+}
@@ -1,19 +1,31 @@
 pub mod boolean;
 pub mod collection;
 pub mod equality;
+pub mod float;
 pub mod hashmap;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod matcher;
 pub mod numeric;
 pub mod option;
+pub mod panic;
 pub mod result;
+pub mod snapshot;
 pub mod string;
 
 // Instead of glob imports, we explicitly export the trait names
 // to avoid conflicts and ambiguities
 pub use boolean::BooleanMatchers;
-pub use collection::{CollectionExtensions, CollectionMatchers};
+pub use collection::{CollectModifier, CollectionExtensions, CollectionMatchers};
 pub use equality::EqualityMatchers;
-pub use hashmap::HashMapMatchers;
+pub use float::{FloatCollectionMatchers, FloatMatchers};
+pub use hashmap::{HashMapMatchers, MapLookup};
+#[cfg(feature = "json")]
+pub use json::{AsJson, JsonMatchers};
+pub use matcher::{MatchResult, Matcher, SatisfyMatchers};
 pub use numeric::NumericMatchers;
 pub use option::OptionMatchers;
+pub use panic::{PanicMatchers, PanicOutcome};
 pub use result::ResultMatchers;
-pub use string::StringMatchers;
+pub use snapshot::SnapshotMatchers;
+pub use string::{CaptureGroup, StringMatchers};
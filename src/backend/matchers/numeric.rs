@@ -1,7 +1,7 @@
 use crate::backend::Assertion;
 use crate::backend::assertions::sentence::AssertionSentence;
-use std::fmt::Debug;
-use std::ops::Range;
+use std::fmt::{Debug, Display};
+use std::ops::RangeBounds;
 
 /// Trait for numeric assertions
 pub trait NumericMatchers<T> {
@@ -12,267 +12,137 @@ pub trait NumericMatchers<T> {
     fn to_be_greater_than_or_equal(self, expected: T) -> Self;
     fn to_be_less_than(self, expected: T) -> Self;
     fn to_be_less_than_or_equal(self, expected: T) -> Self;
-    fn to_be_in_range(self, range: Range<T>) -> Self;
+
+    /// Check if the number lies in any kind of range (`a..b`, `a..=b`, `a..`, `..b`, `..`)
+    fn to_be_in_range<R>(self, range: R) -> Self
+    where
+        R: RangeBounds<T> + Debug;
+
     fn to_be_even(self) -> Self;
     fn to_be_odd(self) -> Self;
 }
 
-/// Helper trait for numeric-like types
-trait AsNumeric {
-    fn is_positive(&self) -> bool;
-    fn is_negative(&self) -> bool;
-    fn is_zero(&self) -> bool;
-    fn is_greater_than(&self, expected: i32) -> bool;
-    fn is_greater_than_or_equal(&self, expected: i32) -> bool;
-    fn is_less_than(&self, expected: i32) -> bool;
-    fn is_less_than_or_equal(&self, expected: i32) -> bool;
-    fn is_in_range(&self, range: Range<i32>) -> bool;
-    fn is_even(&self) -> bool;
-    fn is_odd(&self) -> bool;
-}
-
-// Implementation for i32
-impl AsNumeric for i32 {
-    fn is_positive(&self) -> bool {
-        *self > 0
-    }
-
-    fn is_negative(&self) -> bool {
-        *self < 0
-    }
-
-    fn is_zero(&self) -> bool {
-        *self == 0
-    }
-
-    fn is_greater_than(&self, expected: i32) -> bool {
-        *self > expected
-    }
-
-    fn is_greater_than_or_equal(&self, expected: i32) -> bool {
-        *self >= expected
-    }
-
-    fn is_less_than(&self, expected: i32) -> bool {
-        *self < expected
-    }
-
-    fn is_less_than_or_equal(&self, expected: i32) -> bool {
-        *self <= expected
-    }
-
-    fn is_in_range(&self, range: Range<i32>) -> bool {
-        range.contains(self)
-    }
-
-    fn is_even(&self) -> bool {
-        *self % 2 == 0
-    }
+/// Helper trait for primitive integer types
+trait Integer: Copy + PartialOrd + Debug + Display {
+    const ZERO: Self;
 
-    fn is_odd(&self) -> bool {
-        *self % 2 != 0
-    }
+    fn is_even(self) -> bool;
 }
 
-// Implementation for &i32
-impl AsNumeric for &i32 {
-    fn is_positive(&self) -> bool {
-        **self > 0
-    }
-
-    fn is_negative(&self) -> bool {
-        **self < 0
-    }
-
-    fn is_zero(&self) -> bool {
-        **self == 0
-    }
-
-    fn is_greater_than(&self, expected: i32) -> bool {
-        **self > expected
-    }
-
-    fn is_greater_than_or_equal(&self, expected: i32) -> bool {
-        **self >= expected
-    }
-
-    fn is_less_than(&self, expected: i32) -> bool {
-        **self < expected
-    }
-
-    fn is_less_than_or_equal(&self, expected: i32) -> bool {
-        **self <= expected
-    }
-
-    fn is_in_range(&self, range: Range<i32>) -> bool {
-        range.contains(*self)
-    }
-
-    fn is_even(&self) -> bool {
-        **self % 2 == 0
-    }
+/// Helper trait for numeric-like types, giving access to the underlying integer
+trait AsNumeric {
+    type Number: Integer;
 
-    fn is_odd(&self) -> bool {
-        **self % 2 != 0
-    }
+    fn number(&self) -> Self::Number;
 }
 
-// Implementation for usize
-impl AsNumeric for usize {
-    fn is_positive(&self) -> bool {
-        *self > 0
-    }
-
-    fn is_negative(&self) -> bool {
-        false // usize cannot be negative
-    }
-
-    fn is_zero(&self) -> bool {
-        *self == 0
-    }
-
-    fn is_greater_than(&self, expected: i32) -> bool {
-        *self > expected as usize
-    }
-
-    fn is_greater_than_or_equal(&self, expected: i32) -> bool {
-        *self >= expected as usize
-    }
-
-    fn is_less_than(&self, expected: i32) -> bool {
-        *self < expected as usize
-    }
-
-    fn is_less_than_or_equal(&self, expected: i32) -> bool {
-        *self <= expected as usize
-    }
-
-    fn is_in_range(&self, range: Range<i32>) -> bool {
-        range.contains(&(*self as i32))
-    }
-
-    fn is_even(&self) -> bool {
-        *self % 2 == 0
-    }
-
-    fn is_odd(&self) -> bool {
-        *self % 2 != 0
-    }
+// Implement the helper traits for an integer primitive and references to it
+macro_rules! impl_as_numeric {
+    ($($int:ty),*) => {
+        $(
+            impl Integer for $int {
+                const ZERO: Self = 0;
+
+                fn is_even(self) -> bool {
+                    self & 1 == 0
+                }
+            }
+
+            impl AsNumeric for $int {
+                type Number = $int;
+
+                fn number(&self) -> $int {
+                    *self
+                }
+            }
+
+            impl AsNumeric for &$int {
+                type Number = $int;
+
+                fn number(&self) -> $int {
+                    **self
+                }
+            }
+        )*
+    };
 }
 
-// Implementation for &usize
-impl AsNumeric for &usize {
-    fn is_positive(&self) -> bool {
-        **self > 0
-    }
-
-    fn is_negative(&self) -> bool {
-        false // usize cannot be negative
-    }
-
-    fn is_zero(&self) -> bool {
-        **self == 0
-    }
-
-    fn is_greater_than(&self, expected: i32) -> bool {
-        **self > expected as usize
-    }
-
-    fn is_greater_than_or_equal(&self, expected: i32) -> bool {
-        **self >= expected as usize
-    }
-
-    fn is_less_than(&self, expected: i32) -> bool {
-        **self < expected as usize
-    }
-
-    fn is_less_than_or_equal(&self, expected: i32) -> bool {
-        **self <= expected as usize
-    }
-
-    fn is_in_range(&self, range: Range<i32>) -> bool {
-        range.contains(&(**self as i32))
-    }
-
-    fn is_even(&self) -> bool {
-        **self % 2 == 0
-    }
-
-    fn is_odd(&self) -> bool {
-        **self % 2 != 0
-    }
-}
+impl_as_numeric!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
 
 // Single implementation for any type that implements AsNumeric
-impl<V> NumericMatchers<i32> for Assertion<V>
+impl<T, V> NumericMatchers<T> for Assertion<V>
 where
-    V: AsNumeric + Debug + Clone,
+    T: Integer,
+    V: AsNumeric<Number = T> + Debug + Clone,
 {
     fn to_be_positive(self) -> Self {
-        let result = self.value.is_positive();
+        let result = self.value.number() > T::ZERO;
         let sentence = AssertionSentence::new("be", "positive");
 
         return self.add_step(sentence, result);
     }
 
     fn to_be_negative(self) -> Self {
-        let result = self.value.is_negative();
+        let result = self.value.number() < T::ZERO;
         let sentence = AssertionSentence::new("be", "negative");
 
         return self.add_step(sentence, result);
     }
 
     fn to_be_zero(self) -> Self {
-        let result = self.value.is_zero();
+        let result = self.value.number() == T::ZERO;
         let sentence = AssertionSentence::new("be", "zero");
 
         return self.add_step(sentence, result);
     }
 
-    fn to_be_greater_than(self, expected: i32) -> Self {
-        let result = self.value.is_greater_than(expected);
+    fn to_be_greater_than(self, expected: T) -> Self {
+        let result = self.value.number() > expected;
         let sentence = AssertionSentence::new("be", format!("greater than {}", expected));
 
         return self.add_step(sentence, result);
     }
 
-    fn to_be_greater_than_or_equal(self, expected: i32) -> Self {
-        let result = self.value.is_greater_than_or_equal(expected);
+    fn to_be_greater_than_or_equal(self, expected: T) -> Self {
+        let result = self.value.number() >= expected;
         let sentence = AssertionSentence::new("be", format!("greater than or equal to {}", expected));
 
         return self.add_step(sentence, result);
     }
 
-    fn to_be_less_than(self, expected: i32) -> Self {
-        let result = self.value.is_less_than(expected);
+    fn to_be_less_than(self, expected: T) -> Self {
+        let result = self.value.number() < expected;
         let sentence = AssertionSentence::new("be", format!("less than {}", expected));
 
         return self.add_step(sentence, result);
     }
 
-    fn to_be_less_than_or_equal(self, expected: i32) -> Self {
-        let result = self.value.is_less_than_or_equal(expected);
+    fn to_be_less_than_or_equal(self, expected: T) -> Self {
+        let result = self.value.number() <= expected;
         let sentence = AssertionSentence::new("be", format!("less than or equal to {}", expected));
 
         return self.add_step(sentence, result);
     }
 
-    fn to_be_in_range(self, range: Range<i32>) -> Self {
-        let result = self.value.is_in_range(range.clone());
-        let sentence = AssertionSentence::new("be", format!("in range {}..{}", range.start, range.end));
+    fn to_be_in_range<R>(self, range: R) -> Self
+    where
+        R: RangeBounds<T> + Debug,
+    {
+        let result = range.contains(&self.value.number());
+        let sentence = AssertionSentence::new("be", format!("in range {:?}", range));
 
         return self.add_step(sentence, result);
     }
 
     fn to_be_even(self) -> Self {
-        let result = self.value.is_even();
+        let result = self.value.number().is_even();
         let sentence = AssertionSentence::new("be", "even");
 
         return self.add_step(sentence, result);
     }
 
     fn to_be_odd(self) -> Self {
-        let result = self.value.is_odd();
+        let result = !self.value.number().is_even();
         let sentence = AssertionSentence::new("be", "odd");
 
         return self.add_step(sentence, result);
@@ -337,6 +207,12 @@ mod tests {
         expect!(2).to_be_greater_than(5);
     }
 
+    #[test]
+    #[should_panic(expected = "expected: be greater than 5\n  received: 2")]
+    fn test_not_greater_reports_received_value() {
+        expect!(2).to_be_greater_than(5);
+    }
+
     #[test]
     #[should_panic(expected = "be greater than or equal to")]
     fn test_not_greater_equal_fails() {
@@ -372,4 +248,57 @@ mod tests {
     fn test_not_odd_fails() {
         expect!(6).to_be_odd();
     }
+
+    #[test]
+    fn test_numeric_matchers_for_all_integer_types() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        expect!(200u8).to_be_greater_than(100);
+        expect!(-3i8).to_be_negative().and().to_be_odd();
+        expect!(60_000u16).to_be_less_than_or_equal(u16::MAX);
+        expect!(-70_000i64).to_be_less_than(-69_999);
+        expect!(u64::MAX).to_be_greater_than(u64::MAX - 1);
+        expect!(i128::MIN).to_be_negative().and().to_be_even();
+        expect!(u128::MAX).to_be_odd();
+        expect!(-1isize).not().to_be_positive();
+        expect!(0u32).to_be_zero().and().not().to_be_negative();
+    }
+
+    #[test]
+    fn test_numeric_matchers_on_references() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        let count: usize = 3_000_000_000;
+        let big: u64 = 1 << 40;
+
+        expect!(&count).to_be_greater_than(2_147_483_648);
+        expect!(&big).to_be_in_range(1 << 39..=1 << 40);
+        expect!(&7i16).to_be_odd();
+    }
+
+    #[test]
+    fn test_in_range_with_all_range_types() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        expect!(10).to_be_in_range(0..=10);
+        expect!(10).not().to_be_in_range(0..10);
+        expect!(10u8).to_be_in_range(5..);
+        expect!(10i64).to_be_in_range(..11);
+        expect!(10u16).to_be_in_range(..);
+    }
+
+    #[test]
+    #[should_panic(expected = "be in range 0..=9")]
+    fn test_not_in_inclusive_range_fails() {
+        expect!(10).to_be_in_range(0..=9);
+    }
+
+    #[test]
+    #[should_panic(expected = "be greater than 18446744073709551615")]
+    fn test_large_unsigned_comparison_fails() {
+        expect!(u64::MAX - 1).to_be_greater_than(u64::MAX);
+    }
 }
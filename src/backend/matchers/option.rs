@@ -9,6 +9,11 @@ pub trait OptionMatchers<T: Debug> {
     fn to_contain(self, expected: &T) -> Self
     where
         T: PartialEq;
+
+    /// Continue the chain on the value inside `Some`
+    ///
+    /// On `None`, the chain is reported as failing and this panics: later matchers would have no value to check.
+    fn some_value(self) -> Assertion<T>;
 }
 
 /// Helper trait for Optiony types
@@ -20,6 +25,7 @@ trait AsOption {
     fn contains_item<U>(&self, expected: &U) -> bool
     where
         U: PartialEq<Self::Item>;
+    fn inner(&self) -> Option<&Self::Item>;
 }
 
 // Implementation for Option<T>
@@ -43,6 +49,10 @@ impl<T: Debug + PartialEq> AsOption for Option<T> {
             None => false,
         }
     }
+
+    fn inner(&self) -> Option<&Self::Item> {
+        self.as_ref()
+    }
 }
 
 // Implementation for &Option<T>
@@ -66,6 +76,10 @@ impl<T: Debug + PartialEq> AsOption for &Option<T> {
             None => false,
         }
     }
+
+    fn inner(&self) -> Option<&Self::Item> {
+        self.as_ref()
+    }
 }
 
 // Single implementation of OptionMatchers for any type that implements AsOption
@@ -76,7 +90,7 @@ where
 {
     fn to_be_some(self) -> Self {
         let result = self.value.is_some_option();
-        let sentence = AssertionSentence::new("be", "some");
+        let sentence = some_sentence();
 
         return self.add_step(sentence, result);
     }
@@ -97,6 +111,18 @@ where
 
         return self.add_step(sentence, result);
     }
+
+    fn some_value(self) -> Assertion<T> {
+        let inner = self.value.inner().cloned();
+        let missing = format!("{} is None, so there is no value to check", self.subject_name());
+
+        return self.continue_with_inner(inner, some_sentence(), AssertionSentence::new("be", "Some whose value"), None, missing);
+    }
+}
+
+/// The sentence of `to_be_some`, also the presence check of `some_value`
+fn some_sentence() -> AssertionSentence {
+    return AssertionSentence::new("be", "some");
 }
 
 #[cfg(test)]
@@ -172,4 +198,62 @@ mod tests {
         let _assertion = expect!(value).to_contain(&42);
         std::hint::black_box(_assertion);
     }
+
+    #[test]
+    fn test_some_value_continues_on_inner_value() {
+        crate::Reporter::disable_deduplication();
+
+        let opt: Option<i32> = Some(5);
+        let name: Option<String> = Some("rest".to_string());
+
+        expect!(opt).to_be_some().some_value().to_be_greater_than(3).and().to_be_odd();
+        expect!(&name).to_be_some().some_value().to_have_length(4);
+        expect!(opt).some_value().not().to_equal(4);
+        expect!(Some(Some(2))).to_be_some().some_value().to_be_some().some_value().to_be_even();
+    }
+
+    #[test]
+    fn test_some_value_sentence() {
+        let opt: Option<i32> = Some(2);
+
+        let mut result = expect!(opt).to_be_some().some_value().to_be_greater_than(3);
+        result.mark_as_intermediate();
+
+        // The presence check is restated by the step on the inner value
+        assert_eq!(result.steps.len(), 1);
+        assert_eq!(result.steps[0].sentence.format_with_conjugation("opt"), "is Some whose value is greater than 3");
+        assert_eq!(result.steps[0].actual.as_deref(), Some("2"));
+        assert!(!result.calculate_chain_result());
+    }
+
+    #[test]
+    #[should_panic(expected = "be Some whose value is greater than 3\n  expected: be Some whose value is greater than 3\n  received: 2")]
+    fn test_some_value_fails() {
+        let opt: Option<i32> = Some(2);
+        let _assertion = expect!(opt).to_be_some().some_value().to_be_greater_than(3);
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "be some\n  expected: be some\n  received: None")]
+    fn test_some_value_short_circuits_on_none() {
+        let opt: Option<i32> = None;
+        let _assertion = expect!(opt).to_be_some().some_value().to_be_greater_than(3);
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_some_value_short_circuits_in_soft_scope() {
+        let result = std::panic::catch_unwind(|| {
+            soft_assertions(|| {
+                let opt: Option<i32> = None;
+                expect!(opt).some_value().to_be_greater_than(3);
+            });
+        });
+
+        let payload = result.unwrap_err();
+        let message = crate::backend::matchers::panic::panic_message(payload.as_ref()).unwrap().to_string();
+        assert!(message.starts_with("1 of 1 soft assertion failed:\n  1. opt is some"));
+        assert!(message.ends_with("the scope then panicked: opt is None, so there is no value to check"));
+    }
 }
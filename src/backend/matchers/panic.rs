@@ -0,0 +1,290 @@
+use crate::backend::Assertion;
+use crate::backend::assertions::sentence::AssertionSentence;
+use crate::backend::matchers::string::{compile_pattern, error_summary};
+use std::any::{Any, type_name};
+use std::cell::Cell;
+use std::fmt::{self, Debug, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+use std::sync::Once;
+
+/// Installs the panic hook wrapper once per process
+static INSTALL_HOOK: Once = Once::new();
+
+thread_local! {
+    /// Whether panics on this thread are being probed and should not be printed
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Matchers for closures, checking whether calling them panics
+///
+/// The first panic matcher of a chain calls the closure, once; the matchers after it check the same [`PanicOutcome`],
+/// so the closure may capture `&mut` or non-`Clone` state.
+pub trait PanicMatchers {
+    /// Check that calling the closure panics
+    fn to_panic(self) -> Assertion<PanicOutcome>;
+
+    /// Check that calling the closure panics with a message containing the substring
+    fn to_panic_with(self, substring: &str) -> Assertion<PanicOutcome>;
+
+    /// Check that calling the closure panics with a message matching the regular expression
+    fn to_panic_matching(self, pattern: &str) -> Assertion<PanicOutcome>;
+
+    /// Check that calling the closure panics with a payload of type `P`, e.g. from `std::panic::panic_any`
+    fn to_panic_with_payload<P: Any>(self) -> Assertion<PanicOutcome>;
+}
+
+/// What calling the closure of a panic assertion did, kept for the later matchers of its chain
+#[derive(Clone)]
+pub struct PanicOutcome(Rc<Probe>);
+
+impl Debug for PanicOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.0.describe());
+    }
+}
+
+/// Outcome of calling a closure under `catch_unwind`
+enum Probe {
+    Returned,
+    Panicked(Box<dyn Any + Send>),
+}
+
+impl Probe {
+    /// The panic message, when the closure panicked with a string
+    fn message(&self) -> Option<&str> {
+        return match self {
+            Probe::Returned => None,
+            Probe::Panicked(payload) => panic_message(payload.as_ref()),
+        };
+    }
+
+    /// Describe what happened, for the "received" line of a failure
+    fn describe(&self) -> String {
+        return match (self, self.message()) {
+            (Probe::Returned, _) => "did not panic".to_string(),
+            (Probe::Panicked(_), Some(message)) => format!("panicked with {:?}", message),
+            (Probe::Panicked(_), None) => "panicked with a non-string payload".to_string(),
+        };
+    }
+}
+
+/// Call the closure, catching its panic without printing it
+fn probe<F, R>(closure: F) -> Probe
+where
+    F: FnOnce() -> R,
+{
+    // Wrap the current hook so panics are only hidden on a thread that is probing
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SILENCED.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+
+    let was_silenced = SILENCED.with(|silenced| silenced.replace(true));
+    let outcome = panic::catch_unwind(AssertUnwindSafe(closure));
+    SILENCED.with(|silenced| silenced.set(was_silenced));
+
+    return match outcome {
+        Ok(_) => Probe::Returned,
+        Err(payload) => Probe::Panicked(payload),
+    };
+}
+
+/// Extract the message of a panic payload, when it is a string
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> Option<&str> {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return Some(message);
+    }
+
+    return payload.downcast_ref::<String>().map(String::as_str);
+}
+
+impl<F, R> PanicMatchers for Assertion<F>
+where
+    F: FnOnce() -> R,
+{
+    fn to_panic(self) -> Assertion<PanicOutcome> {
+        return called(self).to_panic();
+    }
+
+    fn to_panic_with(self, substring: &str) -> Assertion<PanicOutcome> {
+        return called(self).to_panic_with(substring);
+    }
+
+    fn to_panic_matching(self, pattern: &str) -> Assertion<PanicOutcome> {
+        return called(self).to_panic_matching(pattern);
+    }
+
+    fn to_panic_with_payload<P: Any>(self) -> Assertion<PanicOutcome> {
+        return called(self).to_panic_with_payload::<P>();
+    }
+}
+
+/// Call the closure of the assertion, continuing the chain on its outcome
+fn called<F, R>(assertion: Assertion<F>) -> Assertion<PanicOutcome>
+where
+    F: FnOnce() -> R,
+{
+    return assertion.map_value(|closure| PanicOutcome(Rc::new(probe(closure))));
+}
+
+impl PanicMatchers for Assertion<PanicOutcome> {
+    fn to_panic(self) -> Assertion<PanicOutcome> {
+        let probe = self.value.0.clone();
+        let result = matches!(*probe, Probe::Panicked(_));
+        let sentence = AssertionSentence::new("panic", "when called");
+
+        return self.add_step_with_actual(sentence, result, None).with_received(probe.describe());
+    }
+
+    fn to_panic_with(self, substring: &str) -> Assertion<PanicOutcome> {
+        let probe = self.value.0.clone();
+        let result = probe.message().is_some_and(|message| message.contains(substring));
+        let sentence = AssertionSentence::new("panic", format!("with message containing {:?}", substring));
+
+        return self.add_step_with_actual(sentence, result, None).with_received(probe.describe());
+    }
+
+    fn to_panic_matching(self, pattern: &str) -> Assertion<PanicOutcome> {
+        let sentence = AssertionSentence::new("panic", format!("with message matching \"{}\"", pattern));
+
+        return match compile_pattern(pattern) {
+            Ok(regex) => {
+                let probe = self.value.0.clone();
+                let result = probe.message().is_some_and(|message| regex.is_match(message));
+                self.add_step_with_actual(sentence, result, None).with_received(probe.describe())
+            }
+            Err(err) => {
+                // An invalid pattern always fails, whether or not the assertion is negated
+                let sentence = sentence.with_qualifier(format!("(invalid regex: {})", error_summary(&err)));
+                let result = self.negated;
+                self.add_step_with_actual(sentence, result, None)
+            }
+        };
+    }
+
+    fn to_panic_with_payload<P: Any>(self) -> Assertion<PanicOutcome> {
+        let probe = self.value.0.clone();
+        let result = matches!(&*probe, Probe::Panicked(payload) if payload.is::<P>());
+        let sentence = AssertionSentence::new("panic", format!("with a payload of type {}", type_name::<P>()));
+
+        return self.add_step_with_actual(sentence, result, None).with_received(probe.describe());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn divide(a: i32, b: i32) -> i32 {
+        if b == 0 {
+            panic!("attempt to divide {} by zero", a);
+        }
+        a / b
+    }
+
+    #[test]
+    fn test_to_panic() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        let divide_by_zero = || divide(1, 0);
+        let divide_by_one = || divide(1, 1);
+
+        expect!(divide_by_zero).to_panic();
+        expect!(divide_by_one).not().to_panic();
+    }
+
+    #[test]
+    fn test_to_panic_with() {
+        crate::Reporter::disable_deduplication();
+
+        let divide_by_zero = || divide(1, 0);
+
+        expect!(divide_by_zero).to_panic_with("by zero");
+        expect!(divide_by_zero).not().to_panic_with("overflow");
+        expect!(|| divide(4, 2)).not().to_panic_with("by zero");
+    }
+
+    #[test]
+    fn test_to_panic_matching() {
+        crate::Reporter::disable_deduplication();
+
+        let divide_by_zero = || divide(7, 0);
+
+        expect!(divide_by_zero).to_panic_matching(r"divide \d+ by zero");
+        expect!(divide_by_zero).not().to_panic_matching(r"^overflow");
+    }
+
+    #[test]
+    fn test_to_panic_with_payload() {
+        crate::Reporter::disable_deduplication();
+
+        let custom = || std::panic::panic_any(42_u8);
+
+        expect!(custom).to_panic_with_payload::<u8>();
+        expect!(custom).not().to_panic_with_payload::<String>();
+        expect!(|| divide(1, 0)).to_panic_with_payload::<String>();
+    }
+
+    #[test]
+    fn test_panic_matchers_chain() {
+        crate::Reporter::disable_deduplication();
+
+        let divide_by_zero = || divide(1, 0);
+
+        expect!(divide_by_zero).to_panic().and().to_panic_with("divide 1");
+    }
+
+    #[test]
+    fn test_chain_calls_the_closure_once() {
+        crate::Reporter::disable_deduplication();
+
+        let mut calls = 0;
+        expect!(|| {
+            calls += 1;
+            divide(calls, 0)
+        })
+        .to_panic()
+        .and()
+        .to_panic_with("divide 1")
+        .and()
+        .not()
+        .to_panic_with_payload::<u8>();
+
+        expect!(calls).to_equal(1);
+    }
+
+    #[test]
+    fn test_closure_consuming_its_captures() {
+        crate::Reporter::disable_deduplication();
+
+        let owned = String::from("consumed");
+        expect!(move || -> () { panic!("{}", owned) }).to_panic_with("consumed");
+    }
+
+    #[test]
+    #[should_panic(expected = "panic when called\n  expected: panic when called\n  received: did not panic")]
+    fn test_no_panic_fails() {
+        let _assertion = expect!(|| divide(1, 1)).to_panic();
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "received: panicked with \"attempt to divide 1 by zero\"")]
+    fn test_wrong_message_fails() {
+        let _assertion = expect!(|| divide(1, 0)).to_panic_with("overflow");
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid regex")]
+    fn test_invalid_pattern_fails() {
+        let _assertion = expect!(|| divide(1, 0)).not().to_panic_matching("(unclosed");
+        std::hint::black_box(_assertion);
+    }
+}
@@ -8,6 +8,16 @@ pub trait ResultMatchers<T: Debug, E: Debug> {
     fn to_be_err(self) -> Self;
     fn to_contain_ok<U: PartialEq<T> + Debug>(self, expected: &U) -> Self;
     fn to_contain_err<U: PartialEq<E> + Debug>(self, expected: &U) -> Self;
+
+    /// Continue the chain on the value inside `Ok`
+    ///
+    /// On `Err`, the chain is reported as failing and this panics: later matchers would have no value to check.
+    fn ok_value(self) -> Assertion<T>;
+
+    /// Continue the chain on the error inside `Err`
+    ///
+    /// On `Ok`, the chain is reported as failing and this panics: later matchers would have no error to check.
+    fn err_value(self) -> Assertion<E>;
 }
 
 /// Helper trait for Result-like types
@@ -16,6 +26,9 @@ trait AsResult<T: Debug + Clone, E: Debug + Clone> {
     fn is_err_result(&self) -> bool;
     fn contains_ok<U: PartialEq<T> + Debug>(&self, expected: &U) -> bool;
     fn contains_err<U: PartialEq<E> + Debug>(&self, expected: &U) -> bool;
+    fn describe_inner(&self) -> String;
+    fn ok_value(&self) -> Option<&T>;
+    fn err_value(&self) -> Option<&E>;
 }
 
 // Implementation for Result<T, E>
@@ -41,6 +54,21 @@ impl<T: Debug + Clone, E: Debug + Clone> AsResult<T, E> for Result<T, E> {
             Err(actual) => expected == actual,
         }
     }
+
+    fn describe_inner(&self) -> String {
+        match self {
+            Ok(value) => format!("ok value {:?}", value),
+            Err(error) => format!("err value {:?}", error),
+        }
+    }
+
+    fn ok_value(&self) -> Option<&T> {
+        self.as_ref().ok()
+    }
+
+    fn err_value(&self) -> Option<&E> {
+        self.as_ref().err()
+    }
 }
 
 // Implementation for &Result<T, E>
@@ -66,6 +94,21 @@ impl<T: Debug + Clone, E: Debug + Clone> AsResult<T, E> for &Result<T, E> {
             Err(actual) => expected == actual,
         }
     }
+
+    fn describe_inner(&self) -> String {
+        match self {
+            Ok(value) => format!("ok value {:?}", value),
+            Err(error) => format!("err value {:?}", error),
+        }
+    }
+
+    fn ok_value(&self) -> Option<&T> {
+        self.as_ref().ok()
+    }
+
+    fn err_value(&self) -> Option<&E> {
+        self.as_ref().err()
+    }
 }
 
 // Single implementation for any type that implements AsResult
@@ -77,31 +120,57 @@ where
 {
     fn to_be_ok(self) -> Self {
         let result = self.value.is_ok_result();
-        let sentence = AssertionSentence::new("be", "ok");
+        let sentence = ok_sentence();
 
         return self.add_step(sentence, result);
     }
 
     fn to_be_err(self) -> Self {
         let result = self.value.is_err_result();
-        let sentence = AssertionSentence::new("be", "err");
+        let sentence = err_sentence();
 
         return self.add_step(sentence, result);
     }
 
     fn to_contain_ok<U: PartialEq<T> + Debug>(self, expected: &U) -> Self {
         let result = self.value.contains_ok(expected);
+        let received = self.value.describe_inner();
         let sentence = AssertionSentence::new("contain", format!("ok value {:?}", expected));
 
-        return self.add_step(sentence, result);
+        return self.add_step(sentence, result).with_received(received);
     }
 
     fn to_contain_err<U: PartialEq<E> + Debug>(self, expected: &U) -> Self {
         let result = self.value.contains_err(expected);
+        let received = self.value.describe_inner();
         let sentence = AssertionSentence::new("contain", format!("err value {:?}", expected));
 
-        return self.add_step(sentence, result);
+        return self.add_step(sentence, result).with_received(received);
     }
+
+    fn ok_value(self) -> Assertion<T> {
+        let inner = self.value.ok_value().cloned();
+        let missing = format!("{} holds {}, so there is no ok value to check", self.subject_name(), self.value.describe_inner());
+
+        return self.continue_with_inner(inner, ok_sentence(), AssertionSentence::new("be", "Ok whose value"), None, missing);
+    }
+
+    fn err_value(self) -> Assertion<E> {
+        let inner = self.value.err_value().cloned();
+        let missing = format!("{} holds {}, so there is no error to check", self.subject_name(), self.value.describe_inner());
+
+        return self.continue_with_inner(inner, err_sentence(), AssertionSentence::new("be", "Err whose error"), None, missing);
+    }
+}
+
+/// The sentence of `to_be_ok`, also the presence check of `ok_value`
+fn ok_sentence() -> AssertionSentence {
+    return AssertionSentence::new("be", "ok");
+}
+
+/// The sentence of `to_be_err`, also the presence check of `err_value`
+fn err_sentence() -> AssertionSentence {
+    return AssertionSentence::new("be", "err");
 }
 
 #[cfg(test)]
@@ -162,6 +231,14 @@ mod tests {
         std::hint::black_box(_assertion);
     }
 
+    #[test]
+    #[should_panic(expected = "received: err value \"error\"")]
+    fn test_contain_ok_on_err_reports_inner_value() {
+        let value: Result<i32, &str> = Err("error");
+        let _assertion = expect!(value).to_contain_ok(&42);
+        std::hint::black_box(_assertion);
+    }
+
     #[test]
     #[should_panic(expected = "not contain ok value")]
     fn test_ok_right_value_not_fails() {
@@ -185,4 +262,41 @@ mod tests {
         let _assertion = expect!(value).not().to_contain_err(&"error");
         std::hint::black_box(_assertion);
     }
+
+    #[test]
+    fn test_ok_value_and_err_value_continue_on_inner_value() {
+        crate::Reporter::disable_deduplication();
+
+        let parsed: Result<i32, String> = Ok(42);
+        let failed: Result<i32, String> = Err("invalid digit".to_string());
+
+        expect!(parsed.clone()).to_be_ok().ok_value().to_equal(42);
+        expect!(&failed).to_be_err().err_value().to_contain("digit");
+    }
+
+    #[test]
+    fn test_err_value_sentence() {
+        let failed: Result<i32, &str> = Err("timeout");
+
+        let mut result = expect!(failed).to_be_err().err_value().to_equal("refused");
+        result.mark_as_intermediate();
+
+        assert_eq!(result.steps[0].sentence.format(), "be Err whose error is equal to \"refused\"");
+    }
+
+    #[test]
+    #[should_panic(expected = "be Ok whose value is equal to 1")]
+    fn test_ok_value_fails() {
+        let parsed: Result<i32, &str> = Ok(2);
+        let _assertion = expect!(parsed).to_be_ok().ok_value().to_equal(1);
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "be err\n  expected: be err\n  received: Ok(2)")]
+    fn test_err_value_short_circuits_on_ok() {
+        let parsed: Result<i32, &str> = Ok(2);
+        let _assertion = expect!(parsed).to_be_err().err_value().to_equal("refused");
+        std::hint::black_box(_assertion);
+    }
 }
@@ -0,0 +1,334 @@
+use crate::backend::Assertion;
+use crate::backend::assertions::comparison::Comparison;
+use crate::backend::assertions::sentence::AssertionSentence;
+use crate::backend::snapshot::{self, SnapshotOutcome};
+use std::fmt::{Debug, Display};
+
+/// Snapshot matchers, comparing the rendering of a value with the one stored by a previous run
+///
+/// Snapshots are stored in a `__snapshots__` directory next to the test file, see `rest::backend::snapshot`.
+pub trait SnapshotMatchers<T> {
+    /// Compare the `{:#?}` rendering of the value with its stored snapshot
+    fn to_match_snapshot(self) -> Self;
+
+    /// Compare the `Display` rendering of the value with its stored snapshot
+    fn to_match_display_snapshot(self) -> Self
+    where
+        T: Display;
+
+    /// Compare the `{:#?}` rendering of the value with a snapshot written in the test, e.g. `r#"..."#`
+    ///
+    /// Multi-line snapshots may be indented with the code. In update mode, an empty or stale literal is rewritten.
+    fn to_match_inline_snapshot(self, expected: &str) -> Self;
+
+    /// Compare the `Display` rendering of the value with a snapshot written in the test
+    fn to_match_inline_display_snapshot(self, expected: &str) -> Self
+    where
+        T: Display;
+}
+
+impl<T> Assertion<T>
+where
+    T: Debug + Clone,
+{
+    /// Check a rendering of the value against the next snapshot of the current test
+    ///
+    /// A negated snapshot is only compared: recording or updating it would make the assertion fail on every run.
+    fn check_snapshot(self, rendered: String) -> Self {
+        let Some(location) = self.location else {
+            return self.fail_without_location(AssertionSentence::new("match", "its snapshot"));
+        };
+
+        let key = snapshot::next_key(&location);
+        let path = snapshot::snapshot_path(&location);
+        let sentence = AssertionSentence::new("match", format!("snapshot {:?}", key));
+
+        if self.negated {
+            return match snapshot::compare(&path, &key, &rendered) {
+                Some(outcome) => self.report_snapshot(sentence, Ok(outcome), rendered, String::new),
+                None => {
+                    // Without a stored snapshot there is nothing the value could differ from
+                    let result = self.negated;
+                    self.add_step(sentence, result).with_received("no snapshot to compare with, negated snapshots are never recorded")
+                }
+            };
+        }
+
+        let outcome = snapshot::check(&path, &key, &rendered, update_snapshots());
+
+        return self.report_snapshot(sentence, outcome, rendered, || format!("cannot write {}", path.display()));
+    }
+
+    /// Check a rendering of the value against the inline snapshot written in the test, rewriting it when `update` is set
+    ///
+    /// A negated snapshot is only compared: rewriting its literal to the value would make the assertion fail.
+    fn check_inline_snapshot(self, expected: &str, rendered: String, update: bool) -> Self {
+        let sentence = AssertionSentence::new("match", "its inline snapshot");
+        let Some(location) = self.location else {
+            return self.fail_without_location(sentence);
+        };
+
+        // Earlier inline snapshot steps of the chain are earlier calls in the source
+        let call = self.steps.iter().filter(|step| step.sentence.verb == sentence.verb && step.sentence.object == sentence.object).count();
+        let outcome = snapshot::check_inline(&location, call, expected, &rendered, update && !self.negated);
+        let rendered = snapshot::normalize_inline(&rendered);
+
+        return self.report_snapshot(sentence, outcome, rendered, || format!("cannot rewrite the inline snapshot at {}", location));
+    }
+
+    /// Snapshots are stored next to the test source, so an assertion without a location cannot have any
+    fn fail_without_location(self, sentence: AssertionSentence) -> Self {
+        let result = self.negated;
+        return self.add_step(sentence, result).with_received("no source location to store the snapshot next to, use expect!");
+    }
+
+    /// Add the step for a snapshot comparison, with a diff when the rendering differs
+    fn report_snapshot(
+        self,
+        sentence: AssertionSentence,
+        outcome: std::io::Result<SnapshotOutcome>,
+        rendered: String,
+        describe_error: impl FnOnce() -> String,
+    ) -> Self {
+        return match outcome {
+            Ok(SnapshotOutcome::Matched | SnapshotOutcome::Recorded | SnapshotOutcome::Updated) => self.add_step(sentence, true),
+            Ok(SnapshotOutcome::Mismatched { expected }) => {
+                let negated = self.negated;
+                let received = if expected.is_empty() {
+                    "no snapshot yet, set REST_UPDATE_SNAPSHOTS=1 to record it"
+                } else {
+                    "a different rendering, set REST_UPDATE_SNAPSHOTS=1 to update the snapshot"
+                };
+                let result = self.add_step(sentence, false).with_received(received);
+
+                // A negated assertion passes here, with no differences worth showing
+                if negated {
+                    return result;
+                }
+                result.with_comparison(Comparison::Text { expected, actual: rendered })
+            }
+            Err(err) => {
+                // A snapshot that cannot be stored always fails, whether or not the assertion is negated
+                let result = self.negated;
+                self.add_step(sentence, result).with_received(format!("{}: {}", describe_error(), err))
+            }
+        };
+    }
+}
+
+/// Whether snapshots that differ are rewritten, see `Config::update_snapshots`
+fn update_snapshots() -> bool {
+    return crate::reporter::GLOBAL_CONFIG.read().map(|config| config.update_snapshots).unwrap_or(false);
+}
+
+impl<T> SnapshotMatchers<T> for Assertion<T>
+where
+    T: Debug + Clone,
+{
+    fn to_match_snapshot(self) -> Self {
+        let rendered = format!("{:#?}", self.value);
+        return self.check_snapshot(rendered);
+    }
+
+    fn to_match_display_snapshot(self) -> Self
+    where
+        T: Display,
+    {
+        let rendered = self.value.to_string();
+        return self.check_snapshot(rendered);
+    }
+
+    fn to_match_inline_snapshot(self, expected: &str) -> Self {
+        let rendered = format!("{:#?}", self.value);
+        return self.check_inline_snapshot(expected, rendered, update_snapshots());
+    }
+
+    fn to_match_inline_display_snapshot(self, expected: &str) -> Self
+    where
+        T: Display,
+    {
+        let rendered = self.value.to_string();
+        return self.check_inline_snapshot(expected, rendered, update_snapshots());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::snapshot::SNAPSHOT_DIR;
+    use crate::backend::{Comparison, SourceLocation};
+    use crate::prelude::*;
+
+    #[derive(Debug, Clone)]
+    #[allow(dead_code)]
+    struct User {
+        name: String,
+        roles: Vec<&'static str>,
+    }
+
+    /// A location in a temporary source file, so no test rewrites the sources in update mode
+    fn temp_location(name: &str) -> SourceLocation {
+        let dir = std::env::temp_dir().join(format!("rest-snapshot-matchers-{}-{}", std::process::id(), name));
+        std::fs::create_dir_all(dir.join(SNAPSHOT_DIR)).unwrap();
+
+        let source: &'static str = Box::leak(dir.join("sample.rs").to_string_lossy().into_owned().into_boxed_str());
+        return SourceLocation::new(source, 1, 1);
+    }
+
+    /// A temporary location whose snapshot file holds `stored` for the current test
+    fn location_with_stored(name: &str, stored: &str) -> SourceLocation {
+        let location = temp_location(name);
+
+        let test = std::thread::current().name().unwrap().to_string();
+        let content = format!("# Rest snapshots\n\n== {} 1 ==\n{}\n== end ==\n", test, stored);
+        std::fs::write(crate::backend::snapshot::snapshot_path(&location), content).unwrap();
+
+        return location;
+    }
+
+    #[test]
+    fn test_to_match_snapshot() {
+        crate::Reporter::disable_deduplication();
+
+        let user = User { name: "Ann".to_string(), roles: vec!["admin", "ops"] };
+
+        expect!(&user).to_match_snapshot();
+        expect!(vec![1, 2, 3]).to_match_snapshot();
+    }
+
+    #[test]
+    fn test_to_match_display_snapshot() {
+        crate::Reporter::disable_deduplication();
+
+        let report = "== totals ==\n\\ 3 passed\n1 failed";
+
+        expect!(report).to_match_display_snapshot();
+        expect!(3.5).to_match_display_snapshot();
+    }
+
+    #[test]
+    fn test_snapshot_mismatch_captures_diff() {
+        let location = location_with_stored("diff", "stored\nlines");
+
+        let mut result = Assertion::new("changed\nlines", "report").with_location(location).to_match_display_snapshot();
+        result.mark_as_intermediate();
+
+        let key = "backend::matchers::snapshot::tests::test_snapshot_mismatch_captures_diff 1";
+        assert!(!result.steps[0].passed);
+        assert_eq!(result.steps[0].sentence.format(), format!("match snapshot {:?}", key));
+        assert_eq!(
+            result.steps[0].comparison,
+            Some(Comparison::Text { expected: "stored\nlines".to_string(), actual: "changed\nlines".to_string() })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "received: a different rendering, set REST_UPDATE_SNAPSHOTS=1 to update the snapshot")]
+    fn test_snapshot_mismatch_fails() {
+        let location = location_with_stored("fails", "41");
+
+        let _assertion = Assertion::new(41 + 1, "answer").with_location(location).to_match_snapshot();
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_negated_snapshot_only_compares() {
+        let location = location_with_stored("negated", "41");
+        let path = crate::backend::snapshot::snapshot_path(&location);
+        let stored = std::fs::read_to_string(&path).unwrap();
+
+        let mut result = Assertion::new(41 + 1, "answer").with_location(location).not().to_match_snapshot();
+        result.mark_as_intermediate();
+
+        assert!(result.steps[0].passed);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), stored);
+    }
+
+    #[test]
+    fn test_negated_snapshot_is_never_recorded() {
+        let location = temp_location("negated-missing");
+        let path = crate::backend::snapshot::snapshot_path(&location);
+        let _ = std::fs::remove_file(&path);
+
+        let mut result = Assertion::new(42, "answer").with_location(location).not().to_match_snapshot();
+        result.mark_as_intermediate();
+
+        assert!(!result.steps[0].passed);
+        assert_eq!(result.steps[0].received.as_deref(), Some("no snapshot to compare with, negated snapshots are never recorded"));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    #[should_panic(expected = "no source location to store the snapshot next to")]
+    fn test_snapshot_without_location_fails() {
+        let _assertion = Assertion::new(1, "value").to_match_snapshot();
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_to_match_inline_snapshot() {
+        crate::Reporter::disable_deduplication();
+
+        let user = User { name: "Ann".to_string(), roles: vec!["admin"] };
+
+        expect!(&user).to_match_inline_snapshot(
+            r#"
+            User {
+                name: "Ann",
+                roles: [
+                    "admin",
+                ],
+            }
+            "#,
+        );
+        expect!(Some(3)).to_match_inline_snapshot(
+            r#"
+            Some(
+                3,
+            )
+            "#,
+        );
+        expect!("done").to_match_inline_display_snapshot("done");
+        expect!(5).to_match_inline_snapshot("5").and().to_match_inline_display_snapshot("5");
+        expect_not!(7).to_match_inline_snapshot("8");
+    }
+
+    #[test]
+    fn test_inline_snapshot_mismatch_captures_diff() {
+        let mut result = Assertion::new(vec![1, 3], "values")
+            .with_location(temp_location("inline-diff"))
+            .to_match_inline_snapshot("[\n    1,\n    2,\n]");
+        result.mark_as_intermediate();
+
+        assert!(!result.steps[0].passed);
+        assert_eq!(result.steps[0].sentence.format(), "match its inline snapshot");
+        assert_eq!(result.steps[0].received.as_deref(), Some("a different rendering, set REST_UPDATE_SNAPSHOTS=1 to update the snapshot"));
+        assert_eq!(
+            result.steps[0].comparison,
+            Some(Comparison::Text { expected: "[\n    1,\n    2,\n]".to_string(), actual: "[\n    1,\n    3,\n]".to_string() })
+        );
+    }
+
+    #[test]
+    fn test_negated_inline_snapshot_is_not_rewritten() {
+        let location = temp_location("inline-negated");
+        let source = "fn test() {\n    expect_not!(7).to_match_inline_snapshot(\"8\");\n}\n";
+        std::fs::write(location.file, source).unwrap();
+
+        let mut result = Assertion::new(7, "7").with_location(SourceLocation::new(location.file, 2, 5)).not().check_inline_snapshot(
+            "8",
+            "7".to_string(),
+            true,
+        );
+        result.mark_as_intermediate();
+
+        assert!(result.steps[0].passed);
+        assert_eq!(std::fs::read_to_string(location.file).unwrap(), source);
+    }
+
+    #[test]
+    #[should_panic(expected = "received: no snapshot yet, set REST_UPDATE_SNAPSHOTS=1 to record it")]
+    fn test_empty_inline_snapshot_fails() {
+        let _assertion = Assertion::new("new", "value").with_location(temp_location("inline-empty")).to_match_inline_display_snapshot("");
+        std::hint::black_box(_assertion);
+    }
+}
@@ -1,6 +1,57 @@
 use crate::backend::Assertion;
 use crate::backend::assertions::sentence::AssertionSentence;
-use std::fmt::Debug;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::sync::Mutex;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Cache of compiled patterns, so a pattern used in many assertions is only compiled once
+static REGEX_CACHE: Lazy<Mutex<HashMap<String, Result<Regex, String>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Compile a pattern, reusing a previously compiled regex when available
+pub(crate) fn compile_pattern(pattern: &str) -> Result<Regex, String> {
+    let mut cache = REGEX_CACHE.lock().unwrap();
+
+    return cache.entry(pattern.to_string()).or_insert_with(|| Regex::new(pattern).map_err(|err| err.to_string())).clone();
+}
+
+/// Regex compile errors span several lines; keep only the final summary line for the sentence
+pub(crate) fn error_summary(err: &str) -> &str {
+    return err.lines().rev().find(|line| !line.trim().is_empty()).unwrap_or(err).trim();
+}
+
+/// Identifies a capture group either by its index or by its name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureGroup<'a> {
+    /// Numbered capture group (0 is the whole match)
+    Index(usize),
+    /// Named capture group, e.g. `(?P<year>\d{4})`
+    Name(&'a str),
+}
+
+impl From<usize> for CaptureGroup<'_> {
+    fn from(index: usize) -> Self {
+        return CaptureGroup::Index(index);
+    }
+}
+
+impl<'a> From<&'a str> for CaptureGroup<'a> {
+    fn from(name: &'a str) -> Self {
+        return CaptureGroup::Name(name);
+    }
+}
+
+impl Display for CaptureGroup<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CaptureGroup::Index(index) => write!(f, "group {}", index),
+            CaptureGroup::Name(name) => write!(f, "group \"{}\"", name),
+        }
+    }
+}
 
 /// Trait for string assertions
 pub trait StringMatchers {
@@ -15,75 +66,143 @@ pub trait StringMatchers {
 
     fn to_start_with(self, prefix: &str) -> Self;
     fn to_end_with(self, suffix: &str) -> Self;
+
+    /// Check if the string matches a regular expression pattern
     fn to_match(self, pattern: &str) -> Self;
+
+    /// Check if the string matches an already compiled regular expression
+    fn to_match_regex(self, regex: &Regex) -> Self;
+
+    /// Check if a capture group of the pattern captured the expected text
+    fn to_capture<'a>(self, pattern: &str, group: impl Into<CaptureGroup<'a>>, expected: &str) -> Self;
+
+    /// Check if the string equals the expected one, comparing their lowercase forms
+    fn to_equal_ignoring_case(self, expected: &str) -> Self;
+
+    /// Check if the string equals the expected one once every whitespace character is removed from both
+    fn to_equal_ignoring_whitespace(self, expected: &str) -> Self;
+
+    /// Check if the string contains a substring, comparing their lowercase forms
+    fn to_contain_ignoring_case(self, substring: &str) -> Self;
+
+    /// Check the number of lines, as split by `str::lines`
+    fn to_have_line_count(self, expected: usize) -> Self;
+
+    /// Check if one of the lines is exactly `line`, without its line ending
+    fn to_contain_line(self, line: &str) -> Self;
+
+    /// Check if the string is empty or only holds whitespace
+    fn to_be_blank(self) -> Self;
+
+    /// Check that the string has no leading or trailing whitespace
+    fn to_be_trimmed(self) -> Self;
+
+    /// Check that the string only holds ASCII characters, as `char::is_ascii`
+    fn to_be_ascii(self) -> Self;
+
+    /// Check that the string is not empty and only holds numeric characters, as `char::is_numeric`
+    fn to_be_numeric(self) -> Self;
+
+    /// Check that the string is not empty and only holds alphanumeric characters, as `char::is_alphanumeric`
+    fn to_be_alphanumeric(self) -> Self;
+
+    /// Check the number of characters, where `to_have_length` counts bytes
+    fn to_have_char_count(self, expected: usize) -> Self;
+
+    /// Check the number of extended grapheme clusters, i.e. the characters as a reader perceives them
+    fn to_have_grapheme_count(self, expected: usize) -> Self;
 }
 
 /// Helper trait for string-like types
+///
+/// Only `as_string_slice` is required; the checks work on the borrowed `str`.
 trait AsString {
-    fn is_empty_string(&self) -> bool;
-    fn length_string(&self) -> usize;
-    fn contains_substring(&self, substring: &str) -> bool;
-    fn starts_with_substring(&self, prefix: &str) -> bool;
-    fn ends_with_substring(&self, suffix: &str) -> bool;
-    fn matches_pattern(&self, pattern: &str) -> bool;
-}
+    fn as_string_slice(&self) -> &str;
 
-// Implementation for String
-impl AsString for String {
     fn is_empty_string(&self) -> bool {
-        self.is_empty()
+        self.as_string_slice().is_empty()
     }
 
     fn length_string(&self) -> usize {
-        self.len()
+        self.as_string_slice().len()
     }
 
     fn contains_substring(&self, substring: &str) -> bool {
-        self.contains(substring)
+        self.as_string_slice().contains(substring)
     }
 
     fn starts_with_substring(&self, prefix: &str) -> bool {
-        self.starts_with(prefix)
+        self.as_string_slice().starts_with(prefix)
     }
 
     fn ends_with_substring(&self, suffix: &str) -> bool {
-        self.ends_with(suffix)
+        self.as_string_slice().ends_with(suffix)
     }
 
-    fn matches_pattern(&self, pattern: &str) -> bool {
-        // Simple implementation - in a real regex impl this would be different
-        self.contains(pattern)
+    fn matches_regex(&self, regex: &Regex) -> bool {
+        regex.is_match(self.as_string_slice())
     }
-}
 
-// Implementation for &str
-impl AsString for &str {
-    fn is_empty_string(&self) -> bool {
-        self.is_empty()
+    fn capture_group(&self, regex: &Regex, group: CaptureGroup) -> Option<String> {
+        let captures = regex.captures(self.as_string_slice())?;
+        let matched = match group {
+            CaptureGroup::Index(index) => captures.get(index),
+            CaptureGroup::Name(name) => captures.name(name),
+        };
+
+        matched.map(|m| m.as_str().to_string())
     }
 
-    fn length_string(&self) -> usize {
-        self.len()
+    /// The first character failing `accepted`, with its index in characters
+    fn first_char_not(&self, accepted: impl Fn(char) -> bool) -> Option<(usize, char)> {
+        self.as_string_slice().chars().enumerate().find(|(_, c)| !accepted(*c))
     }
+}
 
-    fn contains_substring(&self, substring: &str) -> bool {
-        self.contains(substring)
+// Implementation for String
+impl AsString for String {
+    fn as_string_slice(&self) -> &str {
+        self.as_str()
     }
+}
 
-    fn starts_with_substring(&self, prefix: &str) -> bool {
-        self.starts_with(prefix)
+// Implementation for &str
+impl AsString for &str {
+    fn as_string_slice(&self) -> &str {
+        self
     }
+}
 
-    fn ends_with_substring(&self, suffix: &str) -> bool {
-        self.ends_with(suffix)
+// Implementation for Cow<str>, borrowed or owned
+impl AsString for Cow<'_, str> {
+    fn as_string_slice(&self) -> &str {
+        self.as_ref()
     }
+}
 
-    fn matches_pattern(&self, pattern: &str) -> bool {
-        // Simple implementation - in a real regex impl this would be different
-        self.contains(pattern)
+// Implementation for Box<str>
+impl AsString for Box<str> {
+    fn as_string_slice(&self) -> &str {
+        self
     }
 }
 
+/// Remove every whitespace character, so that only the other characters are compared
+fn without_whitespace(text: &str) -> String {
+    return text.chars().filter(|c| !c.is_whitespace()).collect();
+}
+
+/// Render a count with its unit, e.g. "1 line" or "3 lines"
+fn counted(count: usize, unit: &str) -> String {
+    return if count == 1 { format!("1 {}", unit) } else { format!("{} {}s", count, unit) };
+}
+
+/// Describe the first character that breaks a character class check, e.g. "'é' at char 3"
+fn describe_offending(offending: (usize, char)) -> String {
+    let (index, c) = offending;
+    return format!("{:?} at char {}", c, index);
+}
+
 // Single implementation for any type that implements AsString
 impl<V> StringMatchers for Assertion<V>
 where
@@ -101,7 +220,7 @@ where
         let result = actual_length == expected;
         let sentence = AssertionSentence::new("have", format!("length {}", expected));
 
-        return self.add_step(sentence, result);
+        return self.add_step(sentence, result).with_received(format!("length {}", actual_length));
     }
 
     fn to_contain(self, substring: &str) -> Self {
@@ -130,12 +249,155 @@ where
     }
 
     fn to_match(self, pattern: &str) -> Self {
-        // This is a simplified implementation since we can't easily include a regex library
-        let result = self.value.matches_pattern(pattern);
         let sentence = AssertionSentence::new("match", format!("pattern \"{}\"", pattern));
 
+        match compile_pattern(pattern) {
+            Ok(regex) => {
+                let result = self.value.matches_regex(&regex);
+                return self.add_step(sentence, result);
+            }
+            Err(err) => {
+                // An invalid pattern always fails, whether or not the assertion is negated
+                let sentence = sentence.with_qualifier(format!("(invalid regex: {})", error_summary(&err)));
+                let result = self.negated;
+                return self.add_step(sentence, result);
+            }
+        }
+    }
+
+    fn to_match_regex(self, regex: &Regex) -> Self {
+        let result = self.value.matches_regex(regex);
+        let sentence = AssertionSentence::new("match", format!("regex \"{}\"", regex.as_str()));
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_capture<'a>(self, pattern: &str, group: impl Into<CaptureGroup<'a>>, expected: &str) -> Self {
+        let group = group.into();
+        let sentence = AssertionSentence::new("capture", format!("\"{}\" in {} of pattern \"{}\"", expected, group, pattern));
+        let regex = match compile_pattern(pattern) {
+            Ok(regex) => regex,
+            Err(err) => {
+                // An invalid pattern always fails, whether or not the assertion is negated
+                let sentence = sentence.with_qualifier(format!("(invalid regex: {})", error_summary(&err)));
+                let result = self.negated;
+                return self.add_step(sentence, result);
+            }
+        };
+
+        let captured = self.value.capture_group(&regex, group);
+        let result = captured.as_deref() == Some(expected);
+        let qualifier = match &captured {
+            Some(text) => format!("(captured \"{}\")", text),
+            None => "(nothing captured)".to_string(),
+        };
+
+        return self.add_step(sentence.with_qualifier(qualifier), result);
+    }
+
+    fn to_equal_ignoring_case(self, expected: &str) -> Self {
+        let result = self.value.as_string_slice().to_lowercase() == expected.to_lowercase();
+        let sentence = AssertionSentence::new("be", format!("equal to {:?}", expected)).with_qualifier("ignoring case");
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_equal_ignoring_whitespace(self, expected: &str) -> Self {
+        let result = without_whitespace(self.value.as_string_slice()) == without_whitespace(expected);
+        let sentence = AssertionSentence::new("be", format!("equal to {:?}", expected)).with_qualifier("ignoring whitespace");
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_contain_ignoring_case(self, substring: &str) -> Self {
+        let result = self.value.as_string_slice().to_lowercase().contains(&substring.to_lowercase());
+        let sentence = AssertionSentence::new("contain", format!("\"{}\"", substring)).with_qualifier("ignoring case");
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_have_line_count(self, expected: usize) -> Self {
+        let actual_count = self.value.as_string_slice().lines().count();
+        let result = actual_count == expected;
+        let sentence = AssertionSentence::new("have", counted(expected, "line"));
+
+        return self.add_step(sentence, result).with_received(counted(actual_count, "line"));
+    }
+
+    fn to_contain_line(self, line: &str) -> Self {
+        let result = self.value.as_string_slice().lines().any(|actual| actual == line);
+        let sentence = AssertionSentence::new("contain", format!("line {:?}", line));
+
+        return self.add_step(sentence, result);
+    }
+
+    fn to_be_blank(self) -> Self {
+        let offending = self.value.first_char_not(char::is_whitespace);
+        let result = offending.is_none();
+        let sentence = AssertionSentence::new("be", "blank");
+
+        return match offending {
+            Some(offending) => self.add_step(sentence, result).with_received(describe_offending(offending)),
+            None => self.add_step(sentence, result),
+        };
+    }
+
+    fn to_be_trimmed(self) -> Self {
+        let text = self.value.as_string_slice();
+        let result = text.trim() == text;
+        let sentence = AssertionSentence::new("be", "trimmed");
+
         return self.add_step(sentence, result);
     }
+
+    fn to_be_ascii(self) -> Self {
+        let offending = self.value.first_char_not(|c| c.is_ascii());
+        let result = offending.is_none();
+        let sentence = AssertionSentence::new("be", "ASCII");
+
+        return match offending {
+            Some(offending) => self.add_step(sentence, result).with_received(describe_offending(offending)),
+            None => self.add_step(sentence, result),
+        };
+    }
+
+    fn to_be_numeric(self) -> Self {
+        let offending = self.value.first_char_not(char::is_numeric);
+        let result = !self.value.is_empty_string() && offending.is_none();
+        let sentence = AssertionSentence::new("be", "numeric");
+
+        return match offending {
+            Some(offending) => self.add_step(sentence, result).with_received(describe_offending(offending)),
+            None => self.add_step(sentence, result),
+        };
+    }
+
+    fn to_be_alphanumeric(self) -> Self {
+        let offending = self.value.first_char_not(char::is_alphanumeric);
+        let result = !self.value.is_empty_string() && offending.is_none();
+        let sentence = AssertionSentence::new("be", "alphanumeric");
+
+        return match offending {
+            Some(offending) => self.add_step(sentence, result).with_received(describe_offending(offending)),
+            None => self.add_step(sentence, result),
+        };
+    }
+
+    fn to_have_char_count(self, expected: usize) -> Self {
+        let actual_count = self.value.as_string_slice().chars().count();
+        let result = actual_count == expected;
+        let sentence = AssertionSentence::new("have", counted(expected, "char"));
+
+        return self.add_step(sentence, result).with_received(counted(actual_count, "char"));
+    }
+
+    fn to_have_grapheme_count(self, expected: usize) -> Self {
+        let actual_count = self.value.as_string_slice().graphemes(true).count();
+        let result = actual_count == expected;
+        let sentence = AssertionSentence::new("have", counted(expected, "grapheme"));
+
+        return self.add_step(sentence, result).with_received(counted(actual_count, "grapheme"));
+    }
 }
 
 #[cfg(test)]
@@ -186,6 +448,13 @@ mod tests {
         std::hint::black_box(_assertion);
     }
 
+    #[test]
+    #[should_panic(expected = "received: length 5")]
+    fn test_wrong_length_reports_actual_length() {
+        let _assertion = expect!("hello").to_have_length(4);
+        std::hint::black_box(_assertion);
+    }
+
     #[test]
     #[should_panic(expected = "not have length")]
     fn test_right_length_not_fails() {
@@ -292,4 +561,214 @@ mod tests {
         let _assertion = expect!("hello world").to_match("goodbye");
         std::hint::black_box(_assertion);
     }
+
+    #[test]
+    fn test_string_to_match_regex_syntax() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        // These should pass
+        expect!("order-1234").to_match(r"^order-\d{4}$");
+        expect!("order-12a4").not().to_match(r"^order-\d{4}$");
+        expect!(String::from("Hello World")).to_match("(?i)hello world");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid regex")]
+    fn test_invalid_pattern_fails() {
+        let _assertion = expect!("hello").to_match("(unclosed");
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid regex")]
+    fn test_invalid_pattern_not_fails() {
+        let _assertion = expect!("hello").not().to_match("(unclosed");
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_string_to_match_compiled_regex() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        let regex = regex::Regex::new(r"^\w+@\w+\.com$").unwrap();
+
+        // These should pass
+        expect!("user@example.com").to_match_regex(&regex);
+        expect!("not an email").not().to_match_regex(&regex);
+    }
+
+    #[test]
+    #[should_panic(expected = "match regex")]
+    fn test_compiled_regex_mismatch_fails() {
+        let regex = regex::Regex::new(r"^\d+$").unwrap();
+        let _assertion = expect!("abc").to_match_regex(&regex);
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_string_to_capture() {
+        // Disable deduplication for tests
+        crate::Reporter::disable_deduplication();
+
+        // These should pass
+        expect!("2024-05-17").to_capture(r"(\d{4})-(\d{2})-(\d{2})", 2, "05");
+        expect!("2024-05-17").to_capture(r"(?P<year>\d{4})-(?P<month>\d{2})", "year", "2024");
+        expect!("2024-05-17").not().to_capture(r"(?P<year>\d{4})", "year", "1999");
+    }
+
+    #[test]
+    #[should_panic(expected = "(captured \"2024\")")]
+    fn test_wrong_capture_reports_captured_text() {
+        let _assertion = expect!("2024-05-17").to_capture(r"(?P<year>\d{4})", "year", "1999");
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "(nothing captured)")]
+    fn test_capture_without_match_fails() {
+        let _assertion = expect!("no digits").to_capture(r"(\d+)", 1, "42");
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_string_like_types() {
+        use std::borrow::Cow;
+
+        crate::Reporter::disable_deduplication();
+
+        let borrowed: Cow<str> = Cow::Borrowed("hello world");
+        let owned: Cow<str> = Cow::Owned("hello world".to_string());
+        let boxed: Box<str> = "hello world".into();
+
+        expect!(borrowed).to_start_with("hello").and().to_have_length(11);
+        expect!(owned).to_contain("o w").and().not().to_be_empty();
+        expect!(boxed).to_end_with("world").and().to_match(r"^\w+ \w+$");
+    }
+
+    #[test]
+    fn test_string_to_equal_ignoring_case() {
+        crate::Reporter::disable_deduplication();
+
+        expect!("Hello World").to_equal_ignoring_case("hello WORLD");
+        expect!(String::from("ÉCOLE")).to_equal_ignoring_case("école");
+        expect!("Hello").not().to_equal_ignoring_case("Help");
+    }
+
+    #[test]
+    #[should_panic(expected = "be equal to \"help\" ignoring case")]
+    fn test_equal_ignoring_case_fails() {
+        let _assertion = expect!("Hello").to_equal_ignoring_case("help");
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_string_to_equal_ignoring_whitespace() {
+        crate::Reporter::disable_deduplication();
+
+        expect!("  a b\n\tc ").to_equal_ignoring_whitespace("abc");
+        expect!("SELECT *\n  FROM users").to_equal_ignoring_whitespace("SELECT * FROM users");
+        expect!("a b c").not().to_equal_ignoring_whitespace("a b d");
+    }
+
+    #[test]
+    #[should_panic(expected = "be equal to \"a\\nd\" ignoring whitespace")]
+    fn test_equal_ignoring_whitespace_fails() {
+        let _assertion = expect!("a c").to_equal_ignoring_whitespace("a\nd");
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_string_to_contain_ignoring_case() {
+        crate::Reporter::disable_deduplication();
+
+        expect!("Error: File NOT found").to_contain_ignoring_case("not found");
+        expect!("Error: File NOT found").not().to_contain_ignoring_case("denied");
+    }
+
+    #[test]
+    fn test_string_line_structure() {
+        crate::Reporter::disable_deduplication();
+
+        let text = "first\r\nsecond\nthird\n";
+
+        expect!(text).to_have_line_count(3);
+        expect!("").to_have_line_count(0);
+        expect!(text).to_contain_line("second").and().to_contain_line("first");
+        expect!(text).not().to_contain_line("sec");
+    }
+
+    #[test]
+    #[should_panic(expected = "have 2 lines\n  expected: have 2 lines\n  received: 3 lines")]
+    fn test_wrong_line_count_fails() {
+        let _assertion = expect!("a\nb\nc").to_have_line_count(2);
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_string_to_be_blank_and_trimmed() {
+        crate::Reporter::disable_deduplication();
+
+        expect!("").to_be_blank();
+        expect!(" \t\n").to_be_blank().and().not().to_be_trimmed();
+        expect!("hello").not().to_be_blank().and().to_be_trimmed();
+        expect!(" hello").not().to_be_trimmed();
+        expect!("hello\n").not().to_be_trimmed();
+    }
+
+    #[test]
+    #[should_panic(expected = "be blank\n  expected: be blank\n  received: 'x' at char 2")]
+    fn test_not_blank_fails() {
+        let _assertion = expect!("  x ").to_be_blank();
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_string_character_classes() {
+        crate::Reporter::disable_deduplication();
+
+        expect!("hello, world!").to_be_ascii();
+        expect!("").to_be_ascii();
+        expect!("café").not().to_be_ascii();
+        expect!("12345").to_be_numeric().and().to_be_alphanumeric();
+        expect!("12.5").not().to_be_numeric();
+        expect!("").not().to_be_numeric().and().not().to_be_alphanumeric();
+        expect!("abc123").to_be_alphanumeric().and().not().to_be_numeric();
+        expect!("abc 123").not().to_be_alphanumeric();
+    }
+
+    #[test]
+    #[should_panic(expected = "be ASCII\n  expected: be ASCII\n  received: 'é' at char 3")]
+    fn test_non_ascii_fails() {
+        let _assertion = expect!("café").to_be_ascii();
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "be numeric\n  expected: be numeric\n  received: '.' at char 2")]
+    fn test_non_numeric_fails() {
+        let _assertion = expect!("12.5").to_be_numeric();
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    fn test_string_char_and_grapheme_counts() {
+        crate::Reporter::disable_deduplication();
+
+        // "é" written as "e" and a combining accent: 2 chars, 1 grapheme, 3 bytes
+        let accented = "e\u{301}";
+        let family = "👨‍👩‍👧";
+
+        expect!(accented).to_have_length(3).and().to_have_char_count(2).and().to_have_grapheme_count(1);
+        expect!(family).to_have_char_count(5).and().to_have_grapheme_count(1);
+        expect!(String::from("héllo")).to_have_char_count(5).and().to_have_length(6);
+    }
+
+    #[test]
+    #[should_panic(expected = "have 2 graphemes\n  expected: have 2 graphemes\n  received: 1 grapheme")]
+    fn test_wrong_grapheme_count_fails() {
+        let _assertion = expect!("e\u{301}").to_have_grapheme_count(2);
+        std::hint::black_box(_assertion);
+    }
 }
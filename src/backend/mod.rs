@@ -4,7 +4,13 @@ pub mod assertions;
 pub mod fixtures;
 pub mod matchers;
 pub mod modifiers;
+pub mod snapshot;
+pub mod soft;
 
+pub use assertions::chain::ChainExpr;
+pub use assertions::comparison::Comparison;
+pub use assertions::location::SourceLocation;
 pub use assertions::sentence::AssertionSentence;
 pub use assertions::{Assertion, AssertionStep, LogicalOp, TestSessionResult};
 pub use fixtures::{is_in_fixture_test, register_setup, register_teardown, run_test_with_fixtures};
+pub use soft::soft_assertions;
@@ -0,0 +1,264 @@
+use crate::backend::Assertion;
+use crate::backend::assertions::sentence::AssertionSentence;
+use crate::backend::matchers::collection::AsCollection;
+use std::fmt::Debug;
+
+/// Number of failing elements listed in a report before the rest are only counted
+const MAX_REPORTED_ELEMENTS: usize = 10;
+
+/// Per-element modifier trait, running a nested assertion against the elements of a collection
+///
+/// The nested assertion never panics on its own: its result for each element is folded into a single step,
+/// whose failure lists the offending indices, e.g. `items[3] (value -2) is not positive`.
+pub trait ElementModifiers<T> {
+    /// Check that every element passes the nested assertion
+    fn each<F>(self, check: F) -> Self
+    where
+        F: Fn(Assertion<T>) -> Assertion<T>;
+
+    /// Check that at least one element passes the nested assertion
+    fn any<F>(self, check: F) -> Self
+    where
+        F: Fn(Assertion<T>) -> Assertion<T>;
+
+    /// Check that no element passes the nested assertion
+    fn none<F>(self, check: F) -> Self
+    where
+        F: Fn(Assertion<T>) -> Assertion<T>;
+}
+
+/// Outcome of the nested assertion for one element
+struct ElementCheck {
+    index: usize,
+    value: String,
+    passed: bool,
+    /// Why the element passed or failed, in the third person singular
+    reason: String,
+}
+
+/// The nested assertion applied to every element, with its description
+struct ElementChecks {
+    /// The nested chain conjugated for "elements", e.g. "are positive"
+    plural: String,
+    /// The nested chain conjugated for "element", e.g. "is positive"
+    singular: String,
+    checks: Vec<ElementCheck>,
+}
+
+impl ElementChecks {
+    /// Run the nested assertion against each element, without letting it report on its own
+    fn run<T, F>(elements: Vec<&T>, expr_str: &'static str, check: F) -> Self
+    where
+        T: Clone + Debug,
+        F: Fn(Assertion<T>) -> Assertion<T>,
+    {
+        let mut descriptions = None;
+        let mut checks = Vec::new();
+
+        for (index, element) in elements.into_iter().enumerate() {
+            let mut start = Assertion::new(element.clone(), expr_str);
+            start.mark_as_intermediate();
+
+            let mut nested = check(start);
+            nested.mark_as_intermediate();
+
+            let passed = nested.calculate_chain_result();
+            let reason = if passed { Self::passed_reason(&nested) } else { Self::failed_reason(&nested) };
+            checks.push(ElementCheck { index, value: format!("{:?}", element), passed, reason });
+
+            // Every element runs the same chain, so any of them describes it
+            if descriptions.is_none() {
+                descriptions = nested
+                    .expression()
+                    .map(|expression| (expression.render(&nested.steps, "elements"), expression.render(&nested.steps, "element")));
+            }
+        }
+
+        // Without elements or steps there is nothing to describe the chain with
+        let (plural, singular) =
+            descriptions.unwrap_or_else(|| ("pass the nested assertion".to_string(), "passes the nested assertion".to_string()));
+
+        return Self { plural, singular, checks };
+    }
+
+    /// Describe what made an element pass, from the steps that passed
+    fn passed_reason<T>(nested: &Assertion<T>) -> String {
+        let sentences: Vec<String> =
+            nested.steps.iter().filter(|step| step.passed).map(|step| step.sentence.format_with_conjugation("element")).collect();
+
+        return sentences.join(" and ");
+    }
+
+    /// Describe what made an element fail, by negating the steps that failed
+    fn failed_reason<T>(nested: &Assertion<T>) -> String {
+        let sentences: Vec<String> = nested
+            .steps
+            .iter()
+            .filter(|step| !step.passed)
+            .map(|step| {
+                let negated = !step.sentence.negated;
+                return step.sentence.clone().with_negation(negated).format_with_conjugation("element");
+            })
+            .collect();
+
+        return sentences.join(" and ");
+    }
+
+    /// List the elements that passed, or failed, as "items[3] (value -2) is not positive"
+    fn report(&self, subject: &str, passed: bool) -> String {
+        let selected: Vec<&ElementCheck> = self.checks.iter().filter(|check| check.passed == passed).collect();
+        let mut lines: Vec<String> = selected
+            .iter()
+            .take(MAX_REPORTED_ELEMENTS)
+            .map(|check| format!("{}[{}] (value {}) {}", subject, check.index, check.value, check.reason))
+            .collect();
+
+        if selected.len() > MAX_REPORTED_ELEMENTS {
+            lines.push(format!("and {} more", selected.len() - MAX_REPORTED_ELEMENTS));
+        }
+
+        return lines.join(", ");
+    }
+
+    fn count(&self, passed: bool) -> usize {
+        return self.checks.iter().filter(|check| check.passed == passed).count();
+    }
+}
+
+impl<T, V> ElementModifiers<T> for Assertion<V>
+where
+    T: Clone + Debug,
+    V: AsCollection<Item = T> + Debug + Clone,
+{
+    fn each<F>(self, check: F) -> Self
+    where
+        F: Fn(Assertion<T>) -> Assertion<T>,
+    {
+        let subject = self.subject_name();
+        let checks = ElementChecks::run(self.value.items(), self.expr_str, check);
+        let result = checks.count(false) == 0;
+        let sentence = AssertionSentence::new("have", format!("only elements that {}", checks.plural));
+
+        let received = if result { format!("all {} elements passed", checks.checks.len()) } else { checks.report(&subject, false) };
+
+        return self.add_step(sentence, result).with_received(received);
+    }
+
+    fn any<F>(self, check: F) -> Self
+    where
+        F: Fn(Assertion<T>) -> Assertion<T>,
+    {
+        let subject = self.subject_name();
+        let checks = ElementChecks::run(self.value.items(), self.expr_str, check);
+        let result = checks.count(true) > 0;
+        let sentence = AssertionSentence::new("have", format!("an element that {}", checks.singular));
+
+        let received = if result { checks.report(&subject, true) } else { checks.report(&subject, false) };
+        let received = if received.is_empty() { "no elements".to_string() } else { received };
+
+        return self.add_step(sentence, result).with_received(received);
+    }
+
+    fn none<F>(self, check: F) -> Self
+    where
+        F: Fn(Assertion<T>) -> Assertion<T>,
+    {
+        let subject = self.subject_name();
+        let checks = ElementChecks::run(self.value.items(), self.expr_str, check);
+        let result = checks.count(true) == 0;
+        let sentence = AssertionSentence::new("have", format!("no element that {}", checks.singular));
+
+        let received = if result { format!("none of {} elements passed", checks.checks.len()) } else { checks.report(&subject, true) };
+
+        return self.add_step(sentence, result).with_received(received);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_each() {
+        crate::Reporter::disable_deduplication();
+
+        let numbers = vec![2, 4, 6];
+        let words = vec!["apple", "avocado"];
+
+        expect!(numbers.clone()).each(|n| n.to_be_positive().and().to_be_even());
+        expect!(&numbers).not().each(|n| n.to_be_greater_than(3));
+        expect!(words.as_slice()).each(|w| w.to_start_with("a"));
+        expect!(Vec::<i32>::new()).each(|n| n.to_be_negative());
+    }
+
+    #[test]
+    fn test_any() {
+        crate::Reporter::disable_deduplication();
+
+        let numbers = vec![-1, 0, 3];
+
+        expect!(&numbers).any(|n| n.to_be_positive());
+        expect!(&numbers).not().any(|n| n.to_be_greater_than(10));
+        expect!(Vec::<i32>::new()).not().any(|n| n.to_be_zero());
+    }
+
+    #[test]
+    fn test_none() {
+        crate::Reporter::disable_deduplication();
+
+        let numbers = vec![1, 3, 5];
+
+        expect!(&numbers).none(|n| n.to_be_even());
+        expect!(&numbers).not().none(|n| n.to_equal(3));
+    }
+
+    #[test]
+    fn test_each_reports_failing_elements() {
+        let items = vec![1, 5, 0, -2];
+
+        let mut result = expect!(items).each(|item| item.to_be_positive());
+        result.mark_as_intermediate();
+
+        assert!(!result.steps[0].passed);
+        assert_eq!(result.steps[0].sentence.format(), "have only elements that are positive");
+        assert_eq!(result.steps[0].received.as_deref(), Some("items[2] (value 0) is not positive, items[3] (value -2) is not positive"));
+    }
+
+    #[test]
+    fn test_none_reports_matching_elements() {
+        let values = vec![1, 2, 3];
+
+        let mut result = expect!(values).none(|value| value.to_be_greater_than(1).and().not().to_equal(3));
+        result.mark_as_intermediate();
+
+        assert_eq!(result.steps[0].sentence.format(), "have no element that is greater than 1 AND is not equal to 3");
+        assert_eq!(result.steps[0].received.as_deref(), Some("values[1] (value 2) is greater than 1 and is not equal to 3"));
+    }
+
+    #[test]
+    fn test_report_is_truncated() {
+        let numbers: Vec<i32> = (0..15).collect();
+
+        let mut result = expect!(numbers).each(|n| n.to_be_negative());
+        result.mark_as_intermediate();
+
+        let received = result.steps[0].received.clone().unwrap();
+        assert!(received.starts_with("numbers[0] (value 0) is not negative, numbers[1] (value 1) is not negative"));
+        assert!(received.ends_with("numbers[9] (value 9) is not negative, and 5 more"));
+    }
+
+    #[test]
+    #[should_panic(expected = "received: items[3] (value -2) is not positive")]
+    fn test_each_fails() {
+        let items = vec![1, 2, 3, -2];
+        let _assertion = expect!(items).each(|item| item.to_be_positive());
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "have an element that is zero")]
+    fn test_any_fails() {
+        let _assertion = expect!(vec![1, 2]).any(|n| n.to_be_zero());
+        std::hint::black_box(_assertion);
+    }
+}
@@ -0,0 +1,235 @@
+use crate::backend::Assertion;
+use crate::backend::LogicalOp;
+use crate::backend::assertions::chain::ChainExpr;
+
+/// Grouping modifier trait for parenthesising parts of an assertion chain
+///
+/// Groups are evaluated on their own before being combined with the rest of the chain, so
+/// `expect!(x).either(|x| x.to_be_less_than(0), |x| x.to_be_greater_than(10)).and().to_be_even()`
+/// reads as `(x < 0 OR x > 10) AND x is even`. A pending `not()` negates the whole group, reported as `not (...)`.
+pub trait GroupModifier<T> {
+    /// Adds the assertions built by the closure as a single parenthesised group
+    fn group<F>(self, build: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+        Self: Sized;
+
+    /// Joins a parenthesised group to the chain with AND
+    fn and_group<F>(self, build: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+        Self: Sized;
+
+    /// Joins a parenthesised group to the chain with OR
+    fn or_group<F>(self, build: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+        Self: Sized;
+
+    /// Adds a group that passes when either of the two alternatives passes
+    fn either<F, G>(self, first: F, second: G) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+        G: FnOnce(Self) -> Self,
+        Self: Sized;
+
+    /// Adds a group that passes when both of the two alternatives pass
+    fn both<F, G>(self, first: F, second: G) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+        G: FnOnce(Self) -> Self,
+        Self: Sized;
+}
+
+impl<T: Clone> GroupModifier<T> for Assertion<T> {
+    fn group<F>(self, build: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        // The group is built on a fresh assertion of the same value, so its steps start at zero
+        let start = Self {
+            value: self.value.clone(),
+            expr_str: self.expr_str,
+            subject: self.subject.clone(),
+            unwrapped: self.unwrapped.clone(),
+            negated: false,
+            steps: Vec::new(),
+            chain: None,
+            in_chain: true,
+            is_final: false,
+            location: self.location,
+        };
+
+        let mut inner = build(start);
+        inner.mark_as_intermediate();
+
+        // Append the group's steps after ours and shift its expression to match; a single step or group needs no
+        // parentheses of its own, unless a pending `not()` negates it
+        let group = inner.expression().map(|expression| {
+            let group = match expression.offset(self.steps.len()) {
+                step @ ChainExpr::Step(_) if !self.negated => step,
+                group @ ChainExpr::Group(_) => group,
+                expression => ChainExpr::Group(Box::new(expression)),
+            };
+
+            return if self.negated { ChainExpr::Not(Box::new(group)) } else { group };
+        });
+        let negated = self.negated && group.is_none();
+        let chain = match group {
+            Some(group) => Some(self.chain_with(group)),
+            None => self.expression(),
+        };
+
+        let mut steps = self.steps.clone();
+        if !inner.steps.is_empty() {
+            steps.extend(inner.steps.iter().cloned());
+
+            // Whatever operator ended the group belongs inside it, not between the group and what follows
+            if let Some(last) = steps.last_mut() {
+                last.logical_op = None;
+            }
+        }
+
+        let mut result = self;
+        result.mark_as_intermediate();
+
+        return Self {
+            value: result.value.clone(),
+            expr_str: result.expr_str,
+            subject: result.subject.clone(),
+            unwrapped: result.unwrapped.clone(),
+            negated, // A pending `not()` negates the group, unless it was empty
+            steps,
+            chain,
+            in_chain: true,
+            is_final: true, // The group may end the chain
+            location: result.location,
+        };
+    }
+
+    fn and_group<F>(mut self, build: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        self.set_last_logic(LogicalOp::And);
+        return self.group(build);
+    }
+
+    fn or_group<F>(mut self, build: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        self.set_last_logic(LogicalOp::Or);
+        return self.group(build);
+    }
+
+    fn either<F, G>(self, first: F, second: G) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+        G: FnOnce(Self) -> Self,
+    {
+        return self.group(|inner| inner.group(first).or_group(second));
+    }
+
+    fn both<F, G>(self, first: F, second: G) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+        G: FnOnce(Self) -> Self,
+    {
+        return self.group(|inner| inner.group(first).and_group(second));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::ChainExpr;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_group_evaluates_before_and() {
+        crate::Reporter::disable_deduplication();
+
+        // (x < 0 OR x > 10) AND x is even
+        let mut result = expect!(12).group(|x| x.to_be_less_than(0).or().to_be_greater_than(10)).and().to_be_even();
+        result.mark_as_intermediate();
+
+        assert!(result.calculate_chain_result());
+        assert_eq!(
+            result.expression(),
+            Some(ChainExpr::All(vec![
+                ChainExpr::Group(Box::new(ChainExpr::Any(vec![ChainExpr::Step(0), ChainExpr::Step(1)]))),
+                ChainExpr::Step(2)
+            ]))
+        );
+
+        // Without the group, AND binds tighter: x < 0 OR (x > 10 AND x is even)
+        let mut flat = expect!(-3).to_be_less_than(0).or().to_be_greater_than(10).and().to_be_even();
+        flat.mark_as_intermediate();
+        assert!(flat.calculate_chain_result());
+
+        let mut grouped = expect!(-3).group(|x| x.to_be_less_than(0).or().to_be_greater_than(10)).and().to_be_even();
+        grouped.mark_as_intermediate();
+        assert!(!grouped.calculate_chain_result());
+    }
+
+    #[test]
+    fn test_and_group_and_or_group() {
+        crate::Reporter::disable_deduplication();
+
+        expect!(5).to_be_positive().and_group(|x| x.to_equal(5).or().to_equal(6));
+        expect!(5).to_be_negative().or_group(|x| x.to_be_odd().and().to_be_less_than(10));
+    }
+
+    #[test]
+    fn test_either_and_both() {
+        crate::Reporter::disable_deduplication();
+
+        let value = 15;
+
+        expect!(value).either(|x| x.to_be_less_than(0), |x| x.to_be_greater_than(10)).and().to_be_odd();
+        expect!(value).both(|x| x.to_be_greater_than(10), |x| x.to_be_odd().or().to_be_less_than(0));
+
+        let mut result = expect!(value).either(|x| x.to_be_less_than(0), |x| x.to_be_even());
+        result.mark_as_intermediate();
+        assert!(!result.calculate_chain_result());
+    }
+
+    #[test]
+    fn test_not_negates_the_group() {
+        crate::Reporter::disable_deduplication();
+
+        expect!(-5).not().group(|x| x.to_be_positive());
+        expect!(5).not().either(|x| x.to_be_negative(), |x| x.to_be_zero()).and().to_be_odd();
+
+        let mut result = expect!(5).not().either(|x| x.to_be_positive(), |x| x.to_be_zero());
+        result.mark_as_intermediate();
+
+        assert!(!result.calculate_chain_result());
+        assert_eq!(
+            result.expression(),
+            Some(ChainExpr::Not(Box::new(ChainExpr::Group(Box::new(ChainExpr::Any(vec![ChainExpr::Step(0), ChainExpr::Step(1)]))))))
+        );
+        assert_eq!(result.expression().unwrap().render(&result.steps, "5"), "not (is positive OR is zero)");
+    }
+
+    #[test]
+    #[should_panic(expected = "be positive")]
+    fn test_not_group_fails() {
+        let _assertion = expect!(5).not().group(|x| x.to_be_positive());
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "be positive")]
+    fn test_not_either_fails() {
+        let _assertion = expect!(5).not().either(|x| x.to_be_positive(), |x| x.to_be_zero());
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "be even")]
+    fn test_group_fails() {
+        let _assertion = expect!(11).group(|x| x.to_be_less_than(0).or().to_be_greater_than(10)).and().to_be_even();
+        std::hint::black_box(_assertion);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::backend::Assertion;
+
+/// Projection modifier trait for continuing a chain on a field or derived value
+///
+/// The projected assertion keeps the steps, source location and expression of the original one, and its steps read
+/// with the projected path as subject: `expect!(&user).map(|u| &u.name, "name").to_equal(..)` reads "user.name is
+/// equal to ..". Projecting through a reference, as with `expect!(&user)`, lets the closure return borrowed fields.
+pub trait MapModifier<T> {
+    /// Continue the chain on the value returned by the projection, named `name` in failure messages
+    fn map<U, F>(self, projection: F, name: &str) -> Assertion<U>
+    where
+        F: FnOnce(&T) -> U;
+}
+
+impl<T: Clone> MapModifier<T> for Assertion<T> {
+    fn map<U, F>(self, projection: F, name: &str) -> Assertion<U>
+    where
+        F: FnOnce(&T) -> U,
+    {
+        // The steps so far now belong to the projected assertion
+        let mut result = self;
+        result.mark_as_intermediate();
+
+        return Assertion {
+            value: projection(&result.value),
+            expr_str: result.expr_str,
+            subject: Some(format!("{}.{}", result.subject_name(), name)),
+            unwrapped: None,
+            negated: result.negated,
+            steps: result.steps.clone(),
+            chain: result.expression(),
+            in_chain: true,
+            is_final: true,
+            location: result.location,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[derive(Debug, Clone)]
+    struct Address {
+        city: String,
+    }
+
+    #[derive(Debug, Clone)]
+    struct User {
+        name: String,
+        age: u32,
+        address: Address,
+    }
+
+    fn user() -> User {
+        return User { name: "Ann".to_string(), age: 32, address: Address { city: "Lyon".to_string() } };
+    }
+
+    #[test]
+    fn test_map() {
+        crate::Reporter::disable_deduplication();
+
+        let user = user();
+
+        expect!(&user).map(|u| &u.name, "name").to_equal(&"Ann".to_string());
+        expect!(&user).map(|u| u.age, "age").to_be_greater_than(18).and().to_be_less_than(65);
+        expect!(user.clone()).map(|u| u.name.len(), "name.len()").to_equal(3);
+        expect!(&user).map(|u| &u.address, "address").map(|a| a.city.as_str(), "city").to_start_with("Ly");
+    }
+
+    #[test]
+    fn test_map_joins_parent_steps() {
+        let user = user();
+
+        let mut result = expect!(&user).map(|u| u.age, "age").to_be_greater_than(18).and().map(|age| age % 2, "parity").to_equal(1);
+        result.mark_as_intermediate();
+
+        assert_eq!(result.steps.len(), 2);
+        assert_eq!(result.steps[0].sentence.subject, "user.age");
+        assert_eq!(result.steps[1].sentence.subject, "user.age.parity");
+        assert_eq!(result.expr_str, "&user");
+        assert!(!result.calculate_chain_result());
+    }
+
+    #[test]
+    fn test_map_keeps_negation() {
+        crate::Reporter::disable_deduplication();
+
+        let user = user();
+
+        expect!(&user).not().map(|u| u.name.as_str(), "name").to_equal("Bob");
+    }
+
+    #[test]
+    #[should_panic(expected = "be equal to \"Bob\"\n  expected: be equal to \"Bob\"\n  received: \"Ann\"")]
+    fn test_map_fails() {
+        let user = user();
+        let _assertion = expect!(&user).map(|u| u.name.as_str(), "name").to_equal("Bob");
+        std::hint::black_box(_assertion);
+    }
+
+    #[test]
+    #[should_panic(expected = "at src/backend/modifiers/map.rs:")]
+    fn test_map_failure_reports_original_location() {
+        let user = user();
+        let _assertion = expect!(&user).map(|u| u.age, "age").to_be_zero();
+        std::hint::black_box(_assertion);
+    }
+}
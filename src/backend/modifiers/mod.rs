@@ -1,7 +1,13 @@
 mod and;
+mod element;
+mod group;
+mod map;
 mod not;
 mod or;
 
 pub use and::*;
+pub use element::*;
+pub use group::*;
+pub use map::*;
 pub use not::*;
 pub use or::*;
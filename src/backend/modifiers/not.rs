@@ -14,10 +14,14 @@ impl<T: Clone> NotModifier<T> for Assertion<T> {
         return Self {
             value: self.value.clone(),
             expr_str: self.expr_str,
+            subject: self.subject.clone(),
+            unwrapped: self.unwrapped.clone(),
             negated: !self.negated,
             steps: self.steps.clone(),
+            chain: self.chain.clone(),
             in_chain: self.in_chain, // Preserve chain status
             is_final: self.is_final, // Preserve finality status
+            location: self.location,
         };
     }
 }
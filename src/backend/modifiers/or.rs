@@ -22,10 +22,14 @@ impl<T: Clone> OrModifier<T> for Assertion<T> {
         return Self {
             value: result.value.clone(),
             expr_str: result.expr_str,
+            subject: result.subject.clone(),
+            unwrapped: result.unwrapped.clone(),
             negated: result.negated,
             steps: result.steps.clone(),
+            chain: result.chain.clone(),
             in_chain: true,  // Always mark as part of a chain
             is_final: false, // This is not the final step - there will be more after 'or()'
+            location: result.location,
         };
     }
 }
@@ -0,0 +1,573 @@
+//! Snapshot storage: stored renderings of values, compared on later runs
+//!
+//! Snapshots of the tests in `tests/api.rs` live in `tests/__snapshots__/api.rs.snap`, one entry per
+//! `to_match_snapshot` call, keyed by the test path and the call's rank in the test, e.g. `api::renders_user 1`.
+//! New entries are recorded on their first run; set `REST_UPDATE_SNAPSHOTS=1` to rewrite entries that differ, and
+//! `REST_REMOVE_OBSOLETE_SNAPSHOTS=1` to remove the obsolete ones when a full run ends.
+//!
+//! Inline snapshots are string literals passed to `to_match_inline_snapshot` in the test source itself. In update
+//! mode, an empty or stale literal is rewritten in the `.rs` file, found from the location recorded by `expect!`.
+
+use crate::backend::SourceLocation;
+use once_cell::sync::Lazy;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Directory holding the snapshot files, next to the test sources
+pub const SNAPSHOT_DIR: &str = "__snapshots__";
+
+/// First line of every snapshot file
+const FILE_HEADER: &str = "# Rest snapshots, rewritten when REST_UPDATE_SNAPSHOTS=1 is set";
+
+/// Prefix of the line opening an entry, followed by its key
+const ENTRY_START: &str = "== ";
+
+/// Line closing an entry
+const ENTRY_END: &str = "== end ==";
+
+/// Snapshot files read in this process, by path
+static FILES: Lazy<Mutex<HashMap<PathBuf, SnapshotFile>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Number of snapshots taken so far by each test, to key the next one
+static COUNTERS: Lazy<Mutex<HashMap<String, usize>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// An inline snapshot rewrite, as the original line of the assertion and the number of lines it added
+type InlineEdit = (u32, i64);
+
+/// Inline snapshots rewritten in each source file
+static INLINE_EDITS: Lazy<Mutex<HashMap<PathBuf, Vec<InlineEdit>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Methods taking an inline snapshot literal, searched for after the location of the assertion
+const INLINE_MATCHERS: [&str; 2] = ["to_match_inline_snapshot(", "to_match_inline_display_snapshot("];
+
+/// How a rendering compares with its stored snapshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotOutcome {
+    /// The stored snapshot is the same
+    Matched,
+    /// There was no stored snapshot, so this rendering was stored
+    Recorded,
+    /// The stored snapshot differed and was rewritten, in update mode
+    Updated,
+    /// The stored snapshot differs
+    Mismatched { expected: String },
+}
+
+/// The entries of one snapshot file, and the ones checked during this run
+#[derive(Debug, Default)]
+struct SnapshotFile {
+    entries: BTreeMap<String, String>,
+    checked: HashSet<String>,
+}
+
+impl SnapshotFile {
+    /// Read a snapshot file; a missing file has no entries
+    fn load(path: &Path) -> Self {
+        let entries = std::fs::read_to_string(path).map(|content| parse_entries(&content)).unwrap_or_default();
+
+        return Self { entries, checked: HashSet::new() };
+    }
+
+    /// Write the entries back, or remove the file once it has none
+    fn save(&self, path: &Path) -> std::io::Result<()> {
+        if self.entries.is_empty() {
+            return match std::fs::remove_file(path) {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err),
+                _ => Ok(()),
+            };
+        }
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        return std::fs::write(path, render_entries(&self.entries));
+    }
+}
+
+/// Parse the entries of a snapshot file
+///
+/// Content lines starting with `==` or `\` were escaped with a leading `\` when written.
+fn parse_entries(content: &str) -> BTreeMap<String, String> {
+    let mut entries = BTreeMap::new();
+    let mut current: Option<(String, Vec<&str>)> = None;
+
+    for line in content.lines() {
+        match current.take() {
+            None => {
+                if let Some(key) = line.strip_prefix(ENTRY_START).and_then(|rest| rest.strip_suffix(" ==")) {
+                    current = Some((key.to_string(), Vec::new()));
+                }
+            }
+            Some((key, lines)) if line == ENTRY_END => {
+                entries.insert(key, lines.join("\n"));
+            }
+            Some((key, mut lines)) => {
+                lines.push(line.strip_prefix('\\').unwrap_or(line));
+                current = Some((key, lines));
+            }
+        }
+    }
+
+    return entries;
+}
+
+/// Render entries in the snapshot file format, sorted by key
+fn render_entries(entries: &BTreeMap<String, String>) -> String {
+    let mut output = format!("{}\n", FILE_HEADER);
+
+    for (key, snapshot) in entries {
+        output.push_str(&format!("\n{}{} ==\n", ENTRY_START, key));
+        for line in snapshot.split('\n') {
+            if line.starts_with("==") || line.starts_with('\\') {
+                output.push('\\');
+            }
+            output.push_str(line);
+            output.push('\n');
+        }
+        output.push_str(ENTRY_END);
+        output.push('\n');
+    }
+
+    return output;
+}
+
+/// The snapshot file for the tests written at `location`
+pub fn snapshot_path(location: &SourceLocation) -> PathBuf {
+    let source = location.resolve_path().unwrap_or_else(|| PathBuf::from(location.file));
+    let file_name = source.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    let dir = source.parent().map(Path::to_path_buf).unwrap_or_default();
+
+    return dir.join(SNAPSHOT_DIR).join(format!("{}.snap", file_name));
+}
+
+/// Key of the next snapshot of the current test, e.g. `tests::renders_user 2` for its second snapshot
+///
+/// Tests are named after their thread; without a test thread, the location of the assertion names the test.
+pub fn next_key(location: &SourceLocation) -> String {
+    let test = match std::thread::current().name() {
+        Some(name) if name != "main" => name.to_string(),
+        _ => format!("{}:{}", location.file, location.line),
+    };
+
+    let mut counters = COUNTERS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let count = counters.entry(test.clone()).or_insert(0);
+    *count += 1;
+
+    return format!("{} {}", test, count);
+}
+
+/// Compare a rendering with the snapshot stored under `key`, recording or rewriting it when needed
+pub fn check(path: &Path, key: &str, actual: &str, update: bool) -> std::io::Result<SnapshotOutcome> {
+    let mut files = FILES.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let file = files.entry(path.to_path_buf()).or_insert_with(|| SnapshotFile::load(path));
+    file.checked.insert(key.to_string());
+
+    let outcome = match file.entries.get(key) {
+        Some(expected) if expected == actual => return Ok(SnapshotOutcome::Matched),
+        Some(_) if update => SnapshotOutcome::Updated,
+        Some(expected) => return Ok(SnapshotOutcome::Mismatched { expected: expected.clone() }),
+        None => SnapshotOutcome::Recorded,
+    };
+
+    file.entries.insert(key.to_string(), actual.to_string());
+    file.save(path)?;
+
+    return Ok(outcome);
+}
+
+/// Compare a rendering with the snapshot stored under `key` without writing anything, or get `None` when there is none
+pub fn compare(path: &Path, key: &str, actual: &str) -> Option<SnapshotOutcome> {
+    let mut files = FILES.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let file = files.entry(path.to_path_buf()).or_insert_with(|| SnapshotFile::load(path));
+    file.checked.insert(key.to_string());
+
+    return file.entries.get(key).map(|expected| {
+        if expected == actual { SnapshotOutcome::Matched } else { SnapshotOutcome::Mismatched { expected: expected.clone() } }
+    });
+}
+
+/// Entries of the snapshot files read in this run that no assertion checked, as `path › key`
+pub fn obsolete_entries() -> Vec<String> {
+    let files = FILES.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut obsolete: Vec<String> = files
+        .iter()
+        .flat_map(|(path, file)| {
+            file.entries.keys().filter(|key| !file.checked.contains(*key)).map(move |key| format!("{} › {}", path.display(), key))
+        })
+        .collect();
+    obsolete.sort();
+
+    return obsolete;
+}
+
+/// Whether the test binary was asked to run only some of its tests, given its arguments without the program name
+///
+/// Filters and `--skip` leave tests out, and `--ignored` runs the ignored ones only. Entries of the tests left out
+/// look obsolete, so they must not be removed.
+pub fn is_filtered_run(args: impl IntoIterator<Item = String>) -> bool {
+    // libtest options followed by a value, which is not a filter
+    const WITH_VALUE: [&str; 6] = ["--test-threads", "--format", "--color", "--logfile", "--shuffle-seed", "-Z"];
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--skip" || arg.starts_with("--skip=") || arg == "--ignored" {
+            return true;
+        }
+        if WITH_VALUE.contains(&arg.as_str()) {
+            args.next();
+        } else if !arg.starts_with('-') {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+/// Remove the obsolete entries from the snapshot files, returning them as `path › key`
+pub fn remove_obsolete_entries() -> Vec<String> {
+    return remove_obsolete_entries_where(|_| true);
+}
+
+/// Remove the obsolete entries from the selected snapshot files
+fn remove_obsolete_entries_where(selected: impl Fn(&Path) -> bool) -> Vec<String> {
+    let mut files = FILES.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut removed = Vec::new();
+
+    for (path, file) in files.iter_mut().filter(|(path, _)| selected(path)) {
+        let obsolete: Vec<String> = file.entries.keys().filter(|key| !file.checked.contains(*key)).cloned().collect();
+        if obsolete.is_empty() {
+            continue;
+        }
+
+        for key in &obsolete {
+            file.entries.remove(key);
+        }
+
+        // A snapshot file that cannot be written keeps its obsolete entries on disk, to be reported again next time
+        let _ = file.save(path);
+        removed.extend(obsolete.into_iter().map(|key| format!("{} › {}", path.display(), key)));
+    }
+    removed.sort();
+
+    return removed;
+}
+
+/// Normalize an inline snapshot: multi-line snapshots drop their first and last blank lines and common indentation
+///
+/// This lets a literal be indented with the test code, and a rendering compare equal to the literal written for it.
+pub fn normalize_inline(snapshot: &str) -> String {
+    if !snapshot.contains('\n') {
+        return snapshot.to_string();
+    }
+
+    let mut lines: Vec<&str> = snapshot.split('\n').collect();
+    if lines.first().is_some_and(|line| line.trim().is_empty()) {
+        lines.remove(0);
+    }
+    if lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let indent = lines.iter().filter(|line| !line.trim().is_empty()).map(|line| line.len() - line.trim_start().len()).min().unwrap_or(0);
+
+    return lines.iter().map(|line| line.get(indent..).unwrap_or("").trim_end()).collect::<Vec<_>>().join("\n");
+}
+
+/// Compare a rendering with an inline snapshot, rewriting the literal in the source file in update mode
+///
+/// `call` is the rank of the inline snapshot matcher in the assertion's chain, starting at 0, so that the literal of
+/// that call is rewritten when a chain checks several inline snapshots.
+pub fn check_inline(
+    location: &SourceLocation,
+    call: usize,
+    expected: &str,
+    actual: &str,
+    update: bool,
+) -> std::io::Result<SnapshotOutcome> {
+    let expected = normalize_inline(expected);
+    let actual = normalize_inline(actual);
+
+    if expected == actual {
+        return Ok(SnapshotOutcome::Matched);
+    }
+    if !update {
+        return Ok(SnapshotOutcome::Mismatched { expected });
+    }
+
+    rewrite_inline(location, call, &actual)?;
+
+    return Ok(if expected.is_empty() { SnapshotOutcome::Recorded } else { SnapshotOutcome::Updated });
+}
+
+/// Replace the literal of the `call`-th inline snapshot matcher of the assertion at `location` with one holding `snapshot`
+fn rewrite_inline(location: &SourceLocation, call: usize, snapshot: &str) -> std::io::Result<()> {
+    let not_found = |what: &str| std::io::Error::new(std::io::ErrorKind::NotFound, format!("{} for the assertion at {}", what, location));
+
+    let path = location.resolve_path().ok_or_else(|| not_found("no source file"))?;
+    let mut edits = INLINE_EDITS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let file_edits = edits.entry(path.clone()).or_default();
+
+    // Earlier rewrites in the file moved the assertion from the line `line!()` reported
+    let shift: i64 = file_edits.iter().filter(|(line, _)| *line < location.line).map(|(_, added)| added).sum();
+    let line = usize::try_from(i64::from(location.line) + shift).unwrap_or(0);
+
+    let source = std::fs::read_to_string(&path)?;
+    let line_start = source.split_inclusive('\n').take(line.saturating_sub(1)).map(str::len).sum::<usize>();
+    let line_text = source.get(line_start..).and_then(|rest| rest.lines().next()).ok_or_else(|| not_found("no source line"))?;
+    let start = line_start + line_text.char_indices().nth(location.column.saturating_sub(1) as usize).map_or(0, |(index, _)| index);
+
+    // The calls of a chain follow each other from the `expect!` on, and earlier ones come first
+    let mut call_end = start;
+    for _ in 0..=call {
+        call_end = INLINE_MATCHERS
+            .iter()
+            .filter_map(|matcher| source[call_end..].find(matcher).map(|index| call_end + index + matcher.len()))
+            .min()
+            .ok_or_else(|| not_found("no inline snapshot matcher"))?;
+    }
+    let call = call_end;
+    let literal_start = call + (source[call..].len() - source[call..].trim_start().len());
+    let literal_end = literal_start + string_literal_len(&source[literal_start..]).ok_or_else(|| not_found("no string literal"))?;
+
+    // Multi-line snapshots are indented like the literal when it starts its own line, as rustfmt lays out long arguments
+    let before_literal = &source[source[..literal_start].rfind('\n').map_or(0, |index| index + 1)..literal_start];
+    let indent: String = before_literal.chars().take_while(|c| c.is_whitespace()).collect();
+    let content_indent = if before_literal.trim().is_empty() { indent.clone() } else { format!("{}    ", indent) };
+    let literal = render_inline_literal(snapshot, &content_indent, &indent);
+
+    let added = literal.matches('\n').count() as i64 - source[literal_start..literal_end].matches('\n').count() as i64;
+    let rewritten = format!("{}{}{}", &source[..literal_start], literal, &source[literal_end..]);
+    std::fs::write(&path, rewritten)?;
+
+    file_edits.push((location.line, added));
+
+    return Ok(());
+}
+
+/// Length of the string or raw string literal at the start of `source`, if there is one
+fn string_literal_len(source: &str) -> Option<usize> {
+    if let Some(raw) = source.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let body = raw[hashes..].strip_prefix('"')?;
+        let closing = format!("\"{}", "#".repeat(hashes));
+
+        return Some(1 + hashes + 1 + body.find(&closing)? + closing.len());
+    }
+
+    let body = source.strip_prefix('"')?;
+    let mut chars = body.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '"' => return Some(1 + index + 1),
+            _ => {}
+        }
+    }
+
+    return None;
+}
+
+/// Render a snapshot as a raw string literal, multi-line snapshots being closed on a line of their own
+fn render_inline_literal(snapshot: &str, content_indent: &str, closing_indent: &str) -> String {
+    let mut hashes = String::from("#");
+    while snapshot.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+
+    if !snapshot.contains('\n') {
+        return format!("r{}\"{}\"{}", hashes, snapshot, hashes);
+    }
+
+    let mut literal = format!("r{}\"\n", hashes);
+    for line in snapshot.split('\n') {
+        if !line.is_empty() {
+            literal.push_str(content_indent);
+            literal.push_str(line);
+        }
+        literal.push('\n');
+    }
+    literal.push_str(&format!("{}\"{}", closing_indent, hashes));
+
+    return literal;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A snapshot file path of its own for each test, in the temporary directory
+    fn temp_snapshot(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rest-snapshots-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        return dir.join(SNAPSHOT_DIR).join("sample.rs.snap");
+    }
+
+    #[test]
+    fn test_entries_round_trip() {
+        let mut entries = BTreeMap::new();
+        entries.insert("tests::a 1".to_string(), "line one\n== end ==\n\\escaped\n".to_string());
+        entries.insert("tests::b 1".to_string(), String::new());
+        entries.insert("tests::c 1".to_string(), "Point {\n    x: 1,\n}".to_string());
+
+        let rendered = render_entries(&entries);
+
+        assert!(rendered.starts_with(FILE_HEADER));
+        assert!(rendered.contains("\n== tests::a 1 ==\nline one\n\\== end ==\n\\\\escaped\n\n== end ==\n"));
+        assert_eq!(parse_entries(&rendered), entries);
+    }
+
+    #[test]
+    fn test_snapshot_path_is_next_to_the_source() {
+        let location = SourceLocation::new(file!(), line!(), 1);
+
+        let path = snapshot_path(&location);
+
+        assert!(path.ends_with("src/backend/__snapshots__/snapshot.rs.snap"));
+    }
+
+    #[test]
+    fn test_next_key_counts_snapshots_per_test() {
+        let location = SourceLocation::new(file!(), line!(), 1);
+
+        assert_eq!(next_key(&location), "backend::snapshot::tests::test_next_key_counts_snapshots_per_test 1");
+        assert_eq!(next_key(&location), "backend::snapshot::tests::test_next_key_counts_snapshots_per_test 2");
+    }
+
+    #[test]
+    fn test_check_records_then_compares() {
+        let path = temp_snapshot("check");
+
+        assert_eq!(check(&path, "t 1", "first", false).unwrap(), SnapshotOutcome::Recorded);
+        assert!(std::fs::read_to_string(&path).unwrap().contains("== t 1 ==\nfirst\n== end ==\n"));
+
+        assert_eq!(check(&path, "t 1", "first", false).unwrap(), SnapshotOutcome::Matched);
+        assert_eq!(check(&path, "t 1", "second", false).unwrap(), SnapshotOutcome::Mismatched { expected: "first".to_string() });
+        assert_eq!(check(&path, "t 1", "second", true).unwrap(), SnapshotOutcome::Updated);
+        assert_eq!(parse_entries(&std::fs::read_to_string(&path).unwrap()).get("t 1").map(String::as_str), Some("second"));
+    }
+
+    #[test]
+    fn test_obsolete_entries() {
+        let path = temp_snapshot("obsolete");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, format!("{}\n\n== kept 1 ==\nsame\n== end ==\n\n== gone 1 ==\nold\n== end ==\n", FILE_HEADER)).unwrap();
+
+        assert_eq!(check(&path, "kept 1", "same", false).unwrap(), SnapshotOutcome::Matched);
+
+        let obsolete = format!("{} › gone 1", path.display());
+        assert!(obsolete_entries().contains(&obsolete));
+        assert_eq!(remove_obsolete_entries_where(|candidate| candidate == path), vec![obsolete.clone()]);
+        assert!(!obsolete_entries().contains(&obsolete));
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("gone 1"));
+    }
+
+    #[test]
+    fn test_is_filtered_run() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+        assert!(!is_filtered_run(args(&[])));
+        assert!(!is_filtered_run(args(&["--nocapture", "--test-threads", "1", "--format", "pretty"])));
+        assert!(!is_filtered_run(args(&["--include-ignored", "-q"])));
+        assert!(is_filtered_run(args(&["api::renders_user"])));
+        assert!(is_filtered_run(args(&["--exact", "api::renders_user"])));
+        assert!(is_filtered_run(args(&["--test-threads", "1", "snapshot"])));
+        assert!(is_filtered_run(args(&["--skip", "slow"])));
+        assert!(is_filtered_run(args(&["--skip=slow"])));
+        assert!(is_filtered_run(args(&["--ignored"])));
+    }
+
+    #[test]
+    fn test_normalize_inline() {
+        assert_eq!(normalize_inline("  kept as is "), "  kept as is ");
+        assert_eq!(normalize_inline("\n        Point {\n            x: 1,\n        }\n        "), "Point {\n    x: 1,\n}");
+        assert_eq!(normalize_inline("a\n\n  b"), "a\n\n  b");
+    }
+
+    #[test]
+    fn test_string_literal_len() {
+        assert_eq!(string_literal_len(r#""a \" b");"#), Some(8));
+        assert_eq!(string_literal_len(r##"r#"a "quoted" b"#);"##), Some(17));
+        assert_eq!(string_literal_len("r\"\");"), Some(3));
+        assert_eq!(string_literal_len("value);"), None);
+        assert_eq!(string_literal_len("\"unterminated"), None);
+    }
+
+    #[test]
+    fn test_render_inline_literal() {
+        assert_eq!(render_inline_literal("3", "", ""), "r#\"3\"#");
+        assert_eq!(render_inline_literal("say \"#1\"", "", ""), "r##\"say \"#1\"\"##");
+        assert_eq!(render_inline_literal("[\n    1,\n]", "        ", "    "), "r#\"\n        [\n            1,\n        ]\n    \"#");
+    }
+
+    #[test]
+    fn test_check_inline_rewrites_the_source() {
+        let dir = std::env::temp_dir().join(format!("rest-inline-snapshots-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("sample.rs");
+        std::fs::write(
+            &path,
+            "fn test() {\n    expect!(a).to_match_inline_snapshot(\"\");\n    expect!(b).to_match_inline_snapshot(\n        r#\"old\"#,\n    );\n}\n",
+        )
+        .unwrap();
+        let file: &'static str = Box::leak(path.to_string_lossy().into_owned().into_boxed_str());
+
+        let first = SourceLocation::new(file, 2, 5);
+        let second = SourceLocation::new(file, 3, 5);
+
+        assert_eq!(check_inline(&first, 0, "", "[\n    1,\n]", false).unwrap(), SnapshotOutcome::Mismatched { expected: String::new() });
+        assert_eq!(check_inline(&first, 0, "", "[\n    1,\n]", true).unwrap(), SnapshotOutcome::Recorded);
+        assert_eq!(check_inline(&second, 0, "old", "new\nlines", true).unwrap(), SnapshotOutcome::Updated);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            concat!(
+                "fn test() {\n",
+                "    expect!(a).to_match_inline_snapshot(r#\"\n        [\n            1,\n        ]\n    \"#);\n",
+                "    expect!(b).to_match_inline_snapshot(\n        r#\"\n        new\n        lines\n        \"#,\n    );\n",
+                "}\n"
+            )
+        );
+
+        assert_eq!(
+            check_inline(&first, 0, "\n        [\n            1,\n        ]\n    ", "[\n    1,\n]", false).unwrap(),
+            SnapshotOutcome::Matched
+        );
+    }
+
+    #[test]
+    fn test_check_inline_rewrites_the_call_of_the_chain() {
+        let dir = std::env::temp_dir().join(format!("rest-inline-snapshot-chains-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("sample.rs");
+        std::fs::write(
+            &path,
+            concat!(
+                "fn test() {\n",
+                "    expect!(a).to_match_inline_snapshot(\"1\").and().to_match_inline_display_snapshot(\"\");\n",
+                "    expect!(b).to_match_inline_snapshot(\"\").or().to_match_inline_snapshot(\"2\");\n",
+                "}\n"
+            ),
+        )
+        .unwrap();
+        let file: &'static str = Box::leak(path.to_string_lossy().into_owned().into_boxed_str());
+
+        let first = SourceLocation::new(file, 2, 5);
+        let second = SourceLocation::new(file, 3, 5);
+
+        assert_eq!(check_inline(&first, 1, "", "one", true).unwrap(), SnapshotOutcome::Recorded);
+        assert_eq!(check_inline(&second, 1, "2", "two", true).unwrap(), SnapshotOutcome::Updated);
+        assert_eq!(check_inline(&second, 0, "", "zero", true).unwrap(), SnapshotOutcome::Recorded);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            concat!(
+                "fn test() {\n",
+                "    expect!(a).to_match_inline_snapshot(\"1\").and().to_match_inline_display_snapshot(r#\"one\"#);\n",
+                "    expect!(b).to_match_inline_snapshot(r#\"zero\"#).or().to_match_inline_snapshot(r#\"two\"#);\n",
+                "}\n"
+            )
+        );
+    }
+}
@@ -0,0 +1,188 @@
+//! Soft assertions: collect every failure in a scope and fail once at the end
+//!
+//! Inside a soft scope, failed assertions are recorded in a `TestSessionResult` instead of panicking.
+//! When the scope ends, a single panic lists each failed assertion with its sentence and location.
+
+use crate::backend::matchers::panic::panic_message;
+use crate::backend::{Assertion, TestSessionResult};
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+
+thread_local! {
+    /// Results of the soft scopes currently open on this thread, innermost last
+    static SOFT_SCOPES: RefCell<Vec<TestSessionResult>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Run a closure in which failing assertions are collected rather than panicking
+///
+/// Once the closure returns, the scope panics with a report of every failed assertion, if any.
+/// A panic raised inside the closure still ends the scope, and its message is appended to the report.
+///
+/// ```
+/// use rest::prelude::*;
+///
+/// let result = std::panic::catch_unwind(|| {
+///     soft_assertions(|| {
+///         expect!(1 + 1).to_equal(3);
+///         expect!("rest").to_have_length(5);
+///     });
+/// });
+///
+/// assert!(result.is_err());
+/// ```
+pub fn soft_assertions<R>(scope: impl FnOnce() -> R) -> R {
+    SOFT_SCOPES.with(|scopes| scopes.borrow_mut().push(TestSessionResult::default()));
+
+    let outcome = panic::catch_unwind(AssertUnwindSafe(scope));
+
+    let session = SOFT_SCOPES.with(|scopes| scopes.borrow_mut().pop()).unwrap_or_default();
+
+    return match outcome {
+        Ok(value) if session.failures.is_empty() => value,
+        Ok(_) => panic!("{}", format_report(&session)),
+        Err(payload) if session.failures.is_empty() => panic::resume_unwind(payload),
+        Err(payload) => match panic_message(payload.as_ref()) {
+            Some(message) => panic!("{}\n\nthe scope then panicked: {}", format_report(&session), message),
+            None => panic::resume_unwind(payload),
+        },
+    };
+}
+
+/// Whether a soft scope is open on this thread
+pub fn is_active() -> bool {
+    return SOFT_SCOPES.with(|scopes| !scopes.borrow().is_empty());
+}
+
+/// Record an evaluated assertion in the innermost soft scope
+pub(crate) fn record(assertion: Assertion<()>, passed: bool) {
+    SOFT_SCOPES.with(|scopes| {
+        if let Some(session) = scopes.borrow_mut().last_mut() {
+            if passed {
+                session.passed_count += 1;
+            } else {
+                session.failed_count += 1;
+                session.failures.push(assertion);
+            }
+        }
+    });
+}
+
+/// Build the panic message listing every failed assertion of a scope
+fn format_report(session: &TestSessionResult) -> String {
+    let total = session.passed_count + session.failed_count;
+    let noun = if total == 1 { "assertion" } else { "assertions" };
+    let mut report = format!("{} of {} soft {} failed:", session.failed_count, total, noun);
+
+    for (index, failure) in session.failures.iter().enumerate() {
+        // Lead with the whole chain, joined and grouped the way it was evaluated, as the console does
+        let subject = match failure.steps.first() {
+            Some(step) if !step.sentence.subject.is_empty() => step.sentence.subject.as_str(),
+            _ => failure.expr_str.trim_start_matches('&'),
+        };
+        if let Some(expression) = failure.expression() {
+            report.push_str(&format!("\n  {}. {} {}", index + 1, subject, expression.render(&failure.steps, subject)));
+        }
+
+        // Then detail each failed step below it
+        for step in failure.steps.iter().filter(|step| !step.passed) {
+            for line in step.expected_received_lines() {
+                report.push_str(&format!("\n       {}", line));
+            }
+        }
+
+        if let Some(location) = &failure.location {
+            report.push_str(&format!("\n     at {}", location));
+        }
+    }
+
+    return report;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn panic_text(result: std::thread::Result<()>) -> String {
+        let payload = result.unwrap_err();
+        return panic_message(payload.as_ref()).unwrap().to_string();
+    }
+
+    #[test]
+    fn test_soft_assertions_pass_through() {
+        let value = soft_assertions(|| {
+            expect!(2 + 2).to_equal(4);
+            42
+        });
+
+        assert_eq!(value, 42);
+        assert!(!is_active());
+    }
+
+    #[test]
+    fn test_soft_assertions_report_every_failure() {
+        let result = panic::catch_unwind(|| {
+            soft_assertions(|| {
+                let code = 500;
+                let body = "error";
+                expect!(code).to_equal(200);
+                expect!(body).to_have_length(5);
+                expect!(body).to_contain("ok");
+            });
+        });
+
+        let message = panic_text(result);
+        assert!(message.starts_with("2 of 3 soft assertions failed:"));
+        assert!(message.contains("\n  1. code is equal to 200\n       expected: be equal to 200\n       received: 500"));
+        assert!(message.contains("\n  2. body contains \"ok\""));
+        assert!(message.contains("at src/backend/soft.rs:"));
+        assert!(!is_active());
+    }
+
+    #[test]
+    fn test_soft_assertions_report_the_chain_as_evaluated() {
+        let result = panic::catch_unwind(|| {
+            soft_assertions(|| {
+                let x = 5;
+                expect!(x).to_be_less_than(0).or().to_be_greater_than(10);
+            });
+        });
+
+        let message = panic_text(result);
+        assert!(message.contains(concat!(
+            "\n  1. x is less than 0 OR is greater than 10",
+            "\n       expected: be less than 0\n       received: 5",
+            "\n       expected: be greater than 10\n       received: 5"
+        )));
+    }
+
+    #[test]
+    fn test_soft_assertions_keep_panic_message() {
+        let result = panic::catch_unwind(|| {
+            soft_assertions(|| {
+                expect!(1).to_equal(2);
+                panic!("boom");
+            });
+        });
+
+        let message = panic_text(result);
+        assert!(message.starts_with("1 of 1 soft assertion failed:"));
+        assert!(message.ends_with("the scope then panicked: boom"));
+    }
+
+    #[test]
+    fn test_nested_soft_assertions() {
+        let result = panic::catch_unwind(|| {
+            soft_assertions(|| {
+                expect!(1).to_equal(2);
+                soft_assertions(|| {
+                    expect!(3).to_equal(4);
+                });
+            });
+        });
+
+        let message = panic_text(result);
+        assert!(message.contains("1. 1 is equal to 2"));
+        assert!(message.contains("the scope then panicked: 1 of 1 soft assertion failed:\n  1. 3 is equal to 4"));
+    }
+}
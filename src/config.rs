@@ -1,12 +1,44 @@
+use std::cell::Cell;
 use std::env;
-use std::sync::Once;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-// Initialization flag to ensure we only initialize once
-static INIT: Once = Once::new();
+// Set once a configuration is applied, so the automatic one from the environment never replaces it
+static APPLIED: AtomicBool = AtomicBool::new(false);
+
+// Event handlers are registered per thread, so each thread initializes once
+thread_local! {
+    static INITIALIZED: Cell<bool> = const { Cell::new(false) };
+}
 
 // Environment variable to control enhanced output
 const ENV_ENHANCED_OUTPUT: &str = "REST_ENHANCED_OUTPUT";
 
+// Environment variable to rewrite snapshots that differ
+const ENV_UPDATE_SNAPSHOTS: &str = "REST_UPDATE_SNAPSHOTS";
+
+// Environment variable to remove obsolete snapshot entries when a full run ends
+const ENV_REMOVE_OBSOLETE_SNAPSHOTS: &str = "REST_REMOVE_OBSOLETE_SNAPSHOTS";
+
+// Environment variable holding the path of the JUnit XML report
+const ENV_JUNIT_OUTPUT: &str = "REST_JUNIT_OUTPUT";
+
+// Environment variable holding the path of the JSON Lines event stream, `-` for the standard output
+const ENV_EVENTS_OUTPUT: &str = "REST_EVENTS_OUTPUT";
+
+// Environment variable set to `true` by GitHub Actions runners
+const ENV_GITHUB_ACTIONS: &str = "GITHUB_ACTIONS";
+
+/// Format of the assertion results printed on the standard output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Colored sentences, when enhanced output is enabled
+    #[default]
+    Console,
+    /// TAP 14 test points, one per assertion, closed by a plan at `Reporter::summarize`
+    Tap,
+}
+
 /// Configuration for Rest's output and behavior
 pub struct Config {
     pub(crate) use_colors: bool,
@@ -14,6 +46,20 @@ pub struct Config {
     pub(crate) show_success_details: bool,
     /// Enable enhanced test output (fluent assertions instead of standard output)
     pub(crate) enhanced_output: bool,
+    /// Show the source lines around a failed assertion
+    pub(crate) show_code_frame: bool,
+    /// Rewrite snapshots that differ instead of failing
+    pub(crate) update_snapshots: bool,
+    /// Remove the obsolete snapshot entries when the process ends, unless the run was filtered
+    pub(crate) remove_obsolete_snapshots: bool,
+    /// Write a JUnit XML report of the assertions to this path
+    pub(crate) junit_output: Option<PathBuf>,
+    /// Stream the assertion events as JSON Lines to this path, `-` for the standard output
+    pub(crate) events_output: Option<PathBuf>,
+    /// Format of the results printed on the standard output
+    pub(crate) output_format: OutputFormat,
+    /// Print GitHub Actions annotations for failures and group the session summary
+    pub(crate) github_annotations: bool,
 }
 
 impl Default for Config {
@@ -30,6 +76,13 @@ impl Clone for Config {
             use_unicode_symbols: self.use_unicode_symbols,
             show_success_details: self.show_success_details,
             enhanced_output: self.enhanced_output,
+            show_code_frame: self.show_code_frame,
+            update_snapshots: self.update_snapshots,
+            remove_obsolete_snapshots: self.remove_obsolete_snapshots,
+            junit_output: self.junit_output.clone(),
+            events_output: self.events_output.clone(),
+            output_format: self.output_format,
+            github_annotations: self.github_annotations,
         }
     }
 }
@@ -46,7 +99,44 @@ impl Config {
             Err(_) => false, // Default to standard output if env var not set
         };
 
-        Self { use_colors: true, use_unicode_symbols: true, show_success_details: true, enhanced_output: enhanced_from_env }
+        // Snapshots are only rewritten on request
+        let update_snapshots_from_env = match env::var(ENV_UPDATE_SNAPSHOTS) {
+            Ok(val) => {
+                let lowercase_val = val.to_lowercase();
+                lowercase_val == "true" || lowercase_val == "1" || lowercase_val == "yes"
+            }
+            Err(_) => false,
+        };
+
+        // Obsolete entries are only removed on request, as only a full run knows which ones are obsolete
+        let remove_obsolete_snapshots_from_env = match env::var(ENV_REMOVE_OBSOLETE_SNAPSHOTS) {
+            Ok(val) => {
+                let lowercase_val = val.to_lowercase();
+                lowercase_val == "true" || lowercase_val == "1" || lowercase_val == "yes"
+            }
+            Err(_) => false,
+        };
+
+        // An empty path disables the report, like an unset variable
+        let junit_output_from_env = env::var_os(ENV_JUNIT_OUTPUT).filter(|path| !path.is_empty()).map(PathBuf::from);
+        let events_output_from_env = env::var_os(ENV_EVENTS_OUTPUT).filter(|path| !path.is_empty()).map(PathBuf::from);
+
+        // Annotations are on by default when running in GitHub Actions
+        let github_actions_from_env = env::var(ENV_GITHUB_ACTIONS).is_ok_and(|val| val == "true");
+
+        Self {
+            use_colors: true,
+            use_unicode_symbols: true,
+            show_success_details: true,
+            enhanced_output: enhanced_from_env,
+            show_code_frame: false,
+            update_snapshots: update_snapshots_from_env,
+            remove_obsolete_snapshots: remove_obsolete_snapshots_from_env,
+            junit_output: junit_output_from_env,
+            events_output: events_output_from_env,
+            output_format: OutputFormat::Console,
+            github_annotations: github_actions_from_env,
+        }
     }
 
     /// Enable or disable colored output
@@ -73,6 +163,59 @@ impl Config {
         self
     }
 
+    /// Show the source lines around a failed assertion, read from disk, under its location
+    pub fn show_code_frame(mut self, enable: bool) -> Self {
+        self.show_code_frame = enable;
+        self
+    }
+
+    /// Rewrite snapshots that differ instead of failing
+    pub fn update_snapshots(mut self, enable: bool) -> Self {
+        self.update_snapshots = enable;
+        self
+    }
+
+    /// Remove the obsolete snapshot entries when the process ends, skipped when the run was filtered
+    pub fn remove_obsolete_snapshots(mut self, enable: bool) -> Self {
+        self.remove_obsolete_snapshots = enable;
+        self
+    }
+
+    /// Write a JUnit XML report of the assertions to `path`, at `Reporter::summarize` and when the process ends
+    pub fn junit_output(mut self, path: impl Into<PathBuf>) -> Self {
+        self.junit_output = Some(path.into());
+        self
+    }
+
+    /// Stream the assertion events as JSON Lines to `path`, or to the standard output when `path` is `-`
+    pub fn events_output(mut self, path: impl Into<PathBuf>) -> Self {
+        self.events_output = Some(path.into());
+        self
+    }
+
+    /// Print the results on the standard output in this format
+    pub fn output_format(mut self, format: OutputFormat) -> Self {
+        self.output_format = format;
+        self
+    }
+
+    /// Print a GitHub Actions `::error` annotation for each failure, and the session summary in a log group
+    ///
+    /// Enabled by default when the `GITHUB_ACTIONS` environment variable is `true`.
+    pub fn github_annotations(mut self, enable: bool) -> Self {
+        self.github_annotations = enable;
+        self
+    }
+
+    /// Whether assertion events are needed, for the enhanced output, the TAP output, annotations or a report file
+    pub(crate) fn emits_events(&self) -> bool {
+        return self.enhanced_output
+            || self.output_format == OutputFormat::Tap
+            || self.github_annotations
+            || self.junit_output.is_some()
+            || self.events_output.is_some();
+    }
+
     /// Apply the configuration
     pub fn apply(self) {
         use crate::reporter::GLOBAL_CONFIG;
@@ -80,9 +223,10 @@ impl Config {
         // Clone self before moving it into the global config
         let config = self.clone();
         *GLOBAL_CONFIG.write().unwrap() = self;
+        APPLIED.store(true, Ordering::SeqCst);
 
-        // Initialize the event system if enhanced output is enabled
-        if config.enhanced_output {
+        // Initialize the event system if enhanced output or a report needs it
+        if config.emits_events() {
             crate::initialize();
         }
     }
@@ -91,18 +235,25 @@ impl Config {
 /// Initialize the Rest system
 /// This is called automatically when needed but can also be called explicitly
 pub fn initialize() {
-    INIT.call_once(|| {
-        // Check if enhanced output is enabled in the config
-        let config = crate::reporter::GLOBAL_CONFIG.read().unwrap();
+    if INITIALIZED.with(Cell::get) {
+        return;
+    }
 
-        if config.enhanced_output {
-            // Initialize event system
-            crate::events::EventEmitter::init();
+    // Check if enhanced output or a report needs the event system
+    if crate::reporter::GLOBAL_CONFIG.read().unwrap().emits_events() {
+        INITIALIZED.with(|initialized| initialized.set(true));
 
-            // Register event handlers
-            crate::Reporter::init();
-        }
-    });
+        // Initialize event system
+        crate::events::EventEmitter::init();
+
+        // Register event handlers
+        crate::Reporter::init();
+    }
+}
+
+/// Check if a configuration was applied, explicitly or from the environment
+pub(crate) fn is_applied() -> bool {
+    return APPLIED.load(Ordering::SeqCst);
 }
 
 /// Check if enhanced output is enabled in the current configuration
@@ -111,6 +262,11 @@ pub fn is_enhanced_output_enabled() -> bool {
     return config.enhanced_output;
 }
 
+/// Check if assertion events are needed, for the enhanced output or for a report file
+pub(crate) fn is_event_system_enabled() -> bool {
+    return crate::reporter::GLOBAL_CONFIG.read().unwrap().emits_events();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,10 +287,12 @@ mod tests {
         let config = Config::new();
 
         // Check default values
-        assert_eq!(config.use_colors, true);
-        assert_eq!(config.use_unicode_symbols, true);
-        assert_eq!(config.show_success_details, true);
-        assert_eq!(config.enhanced_output, false); // Default is false without env var
+        assert!(config.use_colors);
+        assert!(config.use_unicode_symbols);
+        assert!(config.show_success_details);
+        assert!(!config.enhanced_output); // Default is false without env var
+        assert!(!config.show_code_frame);
+        assert_eq!(config.output_format, OutputFormat::Console);
     }
 
     #[test]
@@ -146,7 +304,7 @@ mod tests {
         }
 
         let config = Config::new();
-        assert_eq!(config.enhanced_output, true);
+        assert!(config.enhanced_output);
 
         // Cleanup
         reset_env_var();
@@ -161,7 +319,7 @@ mod tests {
         }
 
         let config = Config::new();
-        assert_eq!(config.enhanced_output, false);
+        assert!(!config.enhanced_output);
 
         // Cleanup
         reset_env_var();
@@ -178,7 +336,7 @@ mod tests {
             env::set_var(ENV_ENHANCED_OUTPUT, "1");
         }
         let config = Config::new();
-        assert_eq!(config.enhanced_output, true);
+        assert!(config.enhanced_output);
 
         // Test "yes" as true
         reset_env_var();
@@ -186,7 +344,7 @@ mod tests {
             env::set_var(ENV_ENHANCED_OUTPUT, "yes");
         }
         let config = Config::new();
-        assert_eq!(config.enhanced_output, true);
+        assert!(config.enhanced_output);
 
         // Test case-insensitivity
         reset_env_var();
@@ -194,7 +352,7 @@ mod tests {
             env::set_var(ENV_ENHANCED_OUTPUT, "TRUE");
         }
         let config = Config::new();
-        assert_eq!(config.enhanced_output, true);
+        assert!(config.enhanced_output);
 
         // Cleanup
         reset_env_var();
@@ -202,17 +360,36 @@ mod tests {
 
     #[test]
     fn test_config_builder_methods() {
-        let config = Config::new().use_colors(false).use_unicode_symbols(false).show_success_details(false).enhanced_output(true);
-
-        assert_eq!(config.use_colors, false);
-        assert_eq!(config.use_unicode_symbols, false);
-        assert_eq!(config.show_success_details, false);
-        assert_eq!(config.enhanced_output, true);
+        let config = Config::new()
+            .use_colors(false)
+            .use_unicode_symbols(false)
+            .show_success_details(false)
+            .enhanced_output(true)
+            .show_code_frame(true)
+            .update_snapshots(true)
+            .remove_obsolete_snapshots(true)
+            .junit_output("target/junit.xml")
+            .events_output("-")
+            .output_format(OutputFormat::Tap)
+            .github_annotations(true);
+
+        assert!(!config.use_colors);
+        assert!(!config.use_unicode_symbols);
+        assert!(!config.show_success_details);
+        assert!(config.enhanced_output);
+        assert!(config.show_code_frame);
+        assert!(config.update_snapshots);
+        assert!(config.remove_obsolete_snapshots);
+        assert_eq!(config.junit_output, Some(PathBuf::from("target/junit.xml")));
+        assert_eq!(config.events_output, Some(PathBuf::from("-")));
+        assert_eq!(config.output_format, OutputFormat::Tap);
+        assert!(config.github_annotations);
+        assert!(config.emits_events());
     }
 
     #[test]
     fn test_config_clone() {
-        let config1 = Config::new().use_colors(false).enhanced_output(true);
+        let config1 = Config::new().use_colors(false).enhanced_output(true).junit_output("junit.xml").events_output("events.jsonl");
 
         let config2 = config1.clone();
 
@@ -221,6 +398,13 @@ mod tests {
         assert_eq!(config1.use_unicode_symbols, config2.use_unicode_symbols);
         assert_eq!(config1.show_success_details, config2.show_success_details);
         assert_eq!(config1.enhanced_output, config2.enhanced_output);
+        assert_eq!(config1.show_code_frame, config2.show_code_frame);
+        assert_eq!(config1.update_snapshots, config2.update_snapshots);
+        assert_eq!(config1.remove_obsolete_snapshots, config2.remove_obsolete_snapshots);
+        assert_eq!(config1.junit_output, config2.junit_output);
+        assert_eq!(config1.events_output, config2.events_output);
+        assert_eq!(config1.output_format, config2.output_format);
+        assert_eq!(config1.github_annotations, config2.github_annotations);
     }
 
     // Note: Testing apply() and initialize() would require mocking or complex setups
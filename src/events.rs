@@ -38,9 +38,13 @@ impl EventEmitter {
     }
 
     /// Emit an event to all registered handlers
+    ///
+    /// The assertion of an event is a report, never evaluated again when dropped, e.g. when a handler keeps a copy until
+    /// its thread ends.
     pub fn emit(event: AssertionEvent) {
         match event {
-            AssertionEvent::Success(assertion) => {
+            AssertionEvent::Success(mut assertion) => {
+                assertion.mark_as_intermediate();
                 SUCCESS_HANDLERS.with(|handlers| {
                     let handlers = handlers.borrow();
                     for handler in handlers.iter() {
@@ -48,7 +52,8 @@ impl EventEmitter {
                     }
                 });
             }
-            AssertionEvent::Failure(assertion) => {
+            AssertionEvent::Failure(mut assertion) => {
+                assertion.mark_as_intermediate();
                 FAILURE_HANDLERS.with(|handlers| {
                     let handlers = handlers.borrow();
                     for handler in handlers.iter() {
@@ -119,7 +124,14 @@ mod tests {
     // Create a test assertion
     fn create_test_assertion() -> Assertion<()> {
         let mut assertion = Assertion::new((), "test_value");
-        assertion.steps.push(AssertionStep { sentence: AssertionSentence::new("be", "test assertion"), passed: true, logical_op: None });
+        assertion.steps.push(AssertionStep {
+            sentence: AssertionSentence::new("be", "test assertion"),
+            passed: true,
+            logical_op: None,
+            actual: None,
+            received: None,
+            comparison: None,
+        });
         assertion
     }
 
@@ -130,7 +142,7 @@ mod tests {
 
         // Check that the initialization worked
         INITIALIZED.with(|initialized| {
-            assert_eq!(*initialized.borrow(), true);
+            assert!(*initialized.borrow());
         });
     }
 
@@ -150,7 +162,7 @@ mod tests {
         EventEmitter::emit(AssertionEvent::Success(assertion));
 
         // Check that the handler was called
-        assert_eq!(*called_clone.borrow(), true);
+        assert!(*called_clone.borrow());
     }
 
     #[test]
@@ -169,7 +181,24 @@ mod tests {
         EventEmitter::emit(AssertionEvent::Failure(assertion));
 
         // Check that the handler was called
-        assert_eq!(*called_clone.borrow(), true);
+        assert!(*called_clone.borrow());
+    }
+
+    #[test]
+    fn test_handlers_receive_copies_that_are_not_evaluated_again() {
+        // Record whether each copy given to the handler would be evaluated when dropped
+        let finals = Rc::new(RefCell::new(Vec::new()));
+        let finals_clone = finals.clone();
+
+        on_success(move |assertion| {
+            finals.borrow_mut().push(assertion.is_final);
+        });
+
+        let assertion = create_test_assertion();
+        EventEmitter::emit(AssertionEvent::Success(assertion));
+
+        assert!(!finals_clone.borrow().is_empty());
+        assert!(finals_clone.borrow().iter().all(|is_final| !is_final));
     }
 
     #[test]
@@ -187,7 +216,7 @@ mod tests {
         EventEmitter::emit(AssertionEvent::SessionCompleted);
 
         // Check that the handler was called
-        assert_eq!(*called_clone.borrow(), true);
+        assert!(*called_clone.borrow());
     }
 
     #[test]
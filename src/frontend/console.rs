@@ -1,8 +1,11 @@
-use crate::backend::LogicalOp;
 use crate::backend::{Assertion, TestSessionResult};
 use crate::config::Config;
+use crate::frontend::diff::render_diff;
 use colored::*;
 
+/// Number of source lines shown above and below a failed assertion in a code frame
+const CODE_FRAME_CONTEXT: usize = 2;
+
 /// Handles rendering of test results to the console
 pub struct ConsoleRenderer {
     config: Config,
@@ -42,7 +45,7 @@ impl ConsoleRenderer {
     }
 
     /// Build a failure details string
-    fn build_failure_details(&self, result: &Assertion<()>) -> String {
+    pub(crate) fn build_failure_details(&self, result: &Assertion<()>) -> String {
         let mut details = String::new();
 
         // Add individual step results with proper formatting
@@ -53,45 +56,51 @@ impl ConsoleRenderer {
 
             // Always indent and add pass/fail prefix
             details.push_str(&format!("  {} {}\n", result_symbol, formatted_sentence));
-        }
 
-        return details;
-    }
+            // Show what was expected and what was actually received for failed steps
+            if !step.passed {
+                for line in step.expected_received_lines() {
+                    details.push_str(&format!("      {}\n", line));
+                }
 
-    /// Build the main assertion message
-    fn build_assertion_message(&self, result: &Assertion<()>) -> String {
-        if result.steps.is_empty() {
-            return "No assertions made".to_string();
+                // Show a structured diff when the matcher captured both sides of a comparison
+                if let Some(comparison) = &step.comparison {
+                    for line in render_diff(comparison, self.config.use_colors) {
+                        details.push_str(&format!("      {}\n", line));
+                    }
+                }
+            }
         }
 
-        // Clean expression string (remove reference symbols)
-        let clean_expr = result.expr_str.trim_start_matches('&');
+        // Point at the assertion in the source, optionally with the surrounding lines
+        if let Some(location) = &result.location {
+            details.push_str(&format!("  at {}\n", location));
 
-        // For single assertions, conjugate based on the subject name
-        if result.steps.len() == 1 {
-            return format!("{} {}", clean_expr, result.steps[0].sentence.format_with_conjugation(result.expr_str));
+            if self.config.show_code_frame
+                && let Some(frame) = location.code_frame(CODE_FRAME_CONTEXT)
+            {
+                for line in frame {
+                    details.push_str(&format!("    {}\n", line));
+                }
+            }
         }
 
-        // Start with the first step and conjugate based on the subject
-        let mut message = format!("{} {}", clean_expr, result.steps[0].sentence.format_with_conjugation(result.expr_str));
-
-        // Add remaining steps with logical operators
-        for i in 1..result.steps.len() {
-            let prev = &result.steps[i - 1];
-            let curr = &result.steps[i];
-
-            let op_str = match prev.logical_op {
-                Some(LogicalOp::And) => " AND ",
-                Some(LogicalOp::Or) => " OR ",
-                None => " [MISSING OP] ",
-            };
+        return details;
+    }
 
-            // For all subsequent parts in a chain, use conjugated verbs with grammatical format for consistency
-            // This makes phrases like "is greater than X AND is less than Y" instead of "is greater than X AND be less than Y"
-            message.push_str(&format!("{}{}", op_str, curr.sentence.format_with_conjugation(result.expr_str)));
-        }
+    /// Build the main assertion message
+    pub(crate) fn build_assertion_message(&self, result: &Assertion<()>) -> String {
+        // Lead with the subject of the first step, which is the cleaned expression unless the value was projected
+        let subject = match result.steps.first() {
+            Some(step) if !step.sentence.subject.is_empty() => step.sentence.subject.as_str(),
+            _ => result.expr_str.trim_start_matches('&'),
+        };
 
-        return message;
+        // Every step uses the conjugated verb, so chains read "is greater than X AND is less than Y"
+        return match result.expression() {
+            Some(expression) => format!("{} {}", subject, expression.render(&result.steps, subject)),
+            None => "No assertions made".to_string(),
+        };
     }
 
     /// Render a full test session result
@@ -124,6 +133,17 @@ impl ConsoleRenderer {
             }
         }
 
+        if !result.obsolete_snapshots.is_empty() {
+            if self.config.remove_obsolete_snapshots {
+                output.push_str("\nObsolete Snapshots (removed when the run ends, unless it is filtered):\n");
+            } else {
+                output.push_str("\nObsolete Snapshots (set REST_REMOVE_OBSOLETE_SNAPSHOTS=1 on a full run to remove them):\n");
+            }
+            for entry in &result.obsolete_snapshots {
+                output.push_str(&format!("  {}\n", entry));
+            }
+        }
+
         return output;
     }
 
@@ -145,9 +165,10 @@ impl ConsoleRenderer {
         // Print the details with appropriate colors
         if self.config.use_colors {
             for line in details.lines() {
-                if line.contains("✓") {
+                let trimmed = line.trim_start();
+                if line.contains("✓") || trimmed.starts_with("expected:") {
                     println!("{}", line.green());
-                } else if line.contains("✗") {
+                } else if line.contains("✗") || trimmed.starts_with("received:") {
                     println!("{}", line.red());
                 } else {
                     println!("{}", line);
@@ -164,3 +185,152 @@ impl ConsoleRenderer {
         println!("{}", self.render_session_summary(result));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::assertions::sentence::AssertionSentence;
+
+    #[test]
+    fn test_render_failure_shows_expected_and_received() {
+        let renderer = ConsoleRenderer::new(Config::new().use_colors(false));
+        let mut assertion = Assertion::new((), "items");
+        assertion.steps.push(crate::backend::AssertionStep {
+            sentence: AssertionSentence::new("have", "length 2"),
+            passed: false,
+            logical_op: None,
+            actual: Some("[1, 2, 3]".to_string()),
+            received: Some("length 3".to_string()),
+            comparison: None,
+        });
+        assertion.is_final = false;
+
+        let (header, details) = renderer.render_failure(&assertion);
+
+        assert_eq!(header, "✗ items have length 2");
+        assert!(details.contains("expected: have length 2\n"));
+        assert!(details.contains("received: length 3\n"));
+        assert!(details.contains("actual value: [1, 2, 3]\n"));
+    }
+
+    #[test]
+    fn test_render_failure_shows_diff_for_comparisons() {
+        let renderer = ConsoleRenderer::new(Config::new().use_colors(false));
+        let mut assertion = Assertion::new((), "lines");
+        assertion.steps.push(crate::backend::AssertionStep {
+            sentence: AssertionSentence::new("be", "equal to \"a\\nb\""),
+            passed: false,
+            logical_op: None,
+            actual: Some("\"a\\nc\"".to_string()),
+            received: None,
+            comparison: Some(crate::backend::Comparison::of_values("a\nb", "a\nc")),
+        });
+        assertion.is_final = false;
+
+        let (_, details) = renderer.render_failure(&assertion);
+
+        assert!(details.contains("      Difference (- expected, + received):\n        a\n      - b\n      + c\n"));
+    }
+
+    #[test]
+    fn test_render_failure_shows_location_and_code_frame() {
+        let mut assertion = Assertion::new((), "value").with_location(crate::backend::SourceLocation::new(file!(), line!(), 25));
+        assertion.steps.push(crate::backend::AssertionStep {
+            sentence: AssertionSentence::new("be", "positive"),
+            passed: false,
+            logical_op: None,
+            actual: Some("-1".to_string()),
+            received: None,
+            comparison: None,
+        });
+        assertion.is_final = false;
+        let location = assertion.location.unwrap().to_string();
+
+        let renderer = ConsoleRenderer::new(Config::new().use_colors(false));
+        let (_, details) = renderer.render_failure(&assertion);
+        assert!(details.ends_with(&format!("  at {}\n", location)));
+
+        let renderer = ConsoleRenderer::new(Config::new().use_colors(false).show_code_frame(true));
+        let (_, details) = renderer.render_failure(&assertion);
+        assert!(details.contains(&format!("  at {}\n    ", location)));
+        assert!(details.contains("let mut assertion = Assertion::new((), \"value\")"));
+
+        let session = TestSessionResult { failed_count: 1, failures: vec![assertion], ..Default::default() };
+        assert!(renderer.render_session_summary(&session).contains(&format!("at {}", location)));
+    }
+
+    #[test]
+    fn test_render_groups_with_parentheses() {
+        use crate::prelude::*;
+
+        let value = 11;
+        let mut chained = expect!(value).either(|x| x.to_be_less_than(0), |x| x.to_be_greater_than(10)).and().to_be_even();
+        chained.mark_as_intermediate();
+
+        let mut assertion = Assertion::new((), "value");
+        assertion.steps = chained.steps.clone();
+        assertion.chain = chained.chain.clone();
+        assertion.is_final = false;
+
+        let renderer = ConsoleRenderer::new(Config::new().use_colors(false));
+        let (header, _) = renderer.render_failure(&assertion);
+
+        assert_eq!(header, "✗ value (is less than 0 OR is greater than 10) AND is even");
+    }
+
+    #[test]
+    fn test_render_negated_groups() {
+        use crate::prelude::*;
+
+        let value = 5;
+        let mut chained = expect!(value).not().either(|x| x.to_be_positive(), |x| x.to_be_zero());
+        chained.mark_as_intermediate();
+
+        let mut assertion = Assertion::new((), "value");
+        assertion.steps = chained.steps.clone();
+        assertion.chain = chained.chain.clone();
+        assertion.is_final = false;
+
+        let renderer = ConsoleRenderer::new(Config::new().use_colors(false));
+        let (header, _) = renderer.render_failure(&assertion);
+
+        assert_eq!(header, "✗ value not (is positive OR is zero)");
+    }
+
+    #[test]
+    fn test_render_projected_steps_with_their_subject() {
+        use crate::prelude::*;
+
+        let user = ("Ann", 32);
+        let mut chained = expect!(user).to_satisfy_fn(|_| true, "be registered").and().map(|u| u.1, "age").to_be_greater_than(40);
+        chained.mark_as_intermediate();
+
+        let mut assertion = Assertion::new((), "user");
+        assertion.steps = chained.steps.clone();
+        assertion.chain = chained.chain.clone();
+        assertion.is_final = false;
+
+        let renderer = ConsoleRenderer::new(Config::new().use_colors(false));
+        let (header, _) = renderer.render_failure(&assertion);
+
+        assert_eq!(header, "✗ user is registered AND user.age is greater than 40");
+    }
+
+    #[test]
+    fn test_render_session_summary_lists_obsolete_snapshots() {
+        let session = TestSessionResult {
+            passed_count: 2,
+            obsolete_snapshots: vec!["tests/__snapshots__/api.rs.snap › api::removed_test 1".to_string()],
+            ..Default::default()
+        };
+
+        let summary =
+            ConsoleRenderer::new(Config::new().use_colors(false).remove_obsolete_snapshots(false)).render_session_summary(&session);
+        assert!(summary.contains("Obsolete Snapshots (set REST_REMOVE_OBSOLETE_SNAPSHOTS=1 on a full run to remove them):\n"));
+        assert!(summary.contains("  tests/__snapshots__/api.rs.snap › api::removed_test 1\n"));
+
+        let summary =
+            ConsoleRenderer::new(Config::new().use_colors(false).remove_obsolete_snapshots(true)).render_session_summary(&session);
+        assert!(summary.contains("Obsolete Snapshots (removed when the run ends, unless it is filtered):\n"));
+    }
+}
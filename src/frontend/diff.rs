@@ -0,0 +1,295 @@
+//! Diff rendering for failed comparisons captured by the backend
+
+use crate::backend::Comparison;
+use crate::backend::assertions::comparison::compare_rendered;
+use colored::*;
+use std::collections::BTreeMap;
+
+/// Inputs larger than this many line pairs skip the LCS table and are shown as a full replacement
+const MAX_LCS_CELLS: usize = 1_000_000;
+
+/// Number of unchanged lines kept around each change in a text diff
+const CONTEXT_LINES: usize = 2;
+
+/// A single edit turning the expected side into the actual side
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffOp {
+    /// Present on both sides, at (expected index, actual index)
+    Equal(usize, usize),
+    /// Only present in the expected side
+    Delete(usize),
+    /// Only present in the actual side
+    Insert(usize),
+}
+
+/// Render a comparison as diff lines, without trailing newlines
+pub(crate) fn render_diff(comparison: &Comparison, use_colors: bool) -> Vec<String> {
+    let mut lines = vec!["Difference (- expected, + received):".to_string()];
+
+    match comparison {
+        Comparison::Text { expected, actual } | Comparison::Pretty { expected, actual } => {
+            let expected: Vec<&str> = expected.split('\n').collect();
+            let actual: Vec<&str> = actual.split('\n').collect();
+            lines.extend(render_text(&expected, &actual, use_colors));
+        }
+        Comparison::Sequence { expected, actual } => lines.extend(render_sequence(expected, actual, use_colors)),
+        Comparison::Map { expected, actual } => lines.extend(render_map(expected, actual, use_colors)),
+    }
+
+    return lines;
+}
+
+/// Line-by-line diff, collapsing long unchanged stretches
+fn render_text(expected: &[&str], actual: &[&str], use_colors: bool) -> Vec<String> {
+    let ops = diff_ops(expected, actual);
+
+    // Keep unchanged lines only when they are close to a change
+    let near_change = |index: usize| {
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = (index + CONTEXT_LINES + 1).min(ops.len());
+        return ops[start..end].iter().any(|op| !matches!(op, DiffOp::Equal(..)));
+    };
+
+    let mut lines = Vec::new();
+    let mut skipped = false;
+    for (index, op) in ops.iter().enumerate() {
+        match *op {
+            DiffOp::Equal(i, _) if near_change(index) => lines.push(paint(format!("  {}", expected[i]), ' ', use_colors)),
+            DiffOp::Equal(..) => {
+                if !skipped {
+                    lines.push(paint("  ...".to_string(), ' ', use_colors));
+                }
+                skipped = true;
+                continue;
+            }
+            DiffOp::Delete(i) => lines.push(paint(format!("- {}", expected[i]), '-', use_colors)),
+            DiffOp::Insert(j) => lines.push(paint(format!("+ {}", actual[j]), '+', use_colors)),
+        }
+        skipped = false;
+    }
+
+    return lines;
+}
+
+/// Element-level diff, pairing removed and inserted elements at the same spot into changes
+fn render_sequence(expected: &[String], actual: &[String], use_colors: bool) -> Vec<String> {
+    let ops = diff_ops(expected, actual);
+
+    let mut lines = Vec::new();
+    let mut deleted = Vec::new();
+    let mut inserted = Vec::new();
+
+    let flush = |deleted: &mut Vec<usize>, inserted: &mut Vec<usize>, lines: &mut Vec<String>| {
+        for pair in 0..deleted.len().max(inserted.len()) {
+            let line = match (deleted.get(pair), inserted.get(pair)) {
+                (Some(&i), Some(&j)) => paint(format!("~ [{}] {} → {}", i, expected[i], actual[j]), '~', use_colors),
+                (Some(&i), None) => paint(format!("- [{}] {}", i, expected[i]), '-', use_colors),
+                (None, Some(&j)) => paint(format!("+ [{}] {}", j, actual[j]), '+', use_colors),
+                (None, None) => unreachable!(),
+            };
+            lines.push(line);
+        }
+        deleted.clear();
+        inserted.clear();
+    };
+
+    for op in ops {
+        match op {
+            DiffOp::Equal(..) => flush(&mut deleted, &mut inserted, &mut lines),
+            DiffOp::Delete(i) => deleted.push(i),
+            DiffOp::Insert(j) => inserted.push(j),
+        }
+    }
+    flush(&mut deleted, &mut inserted, &mut lines);
+
+    return lines;
+}
+
+/// Key-level diff, sorted by key as in the other map failure messages
+fn render_map(expected: &[(String, String)], actual: &[(String, String)], use_colors: bool) -> Vec<String> {
+    let mut entries: BTreeMap<&str, (Option<&str>, Option<&str>)> = BTreeMap::new();
+    for (key, value) in expected {
+        entries.entry(key).or_default().0 = Some(value);
+    }
+    for (key, value) in actual {
+        entries.entry(key).or_default().1 = Some(value);
+    }
+
+    let mut entries: Vec<_> = entries.into_iter().collect();
+    entries.sort_by(|(a, _), (b, _)| compare_rendered(a, b));
+
+    let mut lines = Vec::new();
+    for (key, sides) in entries {
+        match sides {
+            (Some(expected), Some(actual)) if expected != actual => {
+                lines.push(paint(format!("~ {}: {} → {}", key, expected, actual), '~', use_colors));
+            }
+            (Some(expected), None) => lines.push(paint(format!("- {}: {}", key, expected), '-', use_colors)),
+            (None, Some(actual)) => lines.push(paint(format!("+ {}: {}", key, actual), '+', use_colors)),
+            _ => {}
+        }
+    }
+
+    return lines;
+}
+
+/// Colour a diff line by its marker; expected is green and received is red, as in the rest of the output
+fn paint(line: String, marker: char, use_colors: bool) -> String {
+    if !use_colors {
+        return line;
+    }
+
+    return match marker {
+        '-' => line.green().to_string(),
+        '+' => line.red().to_string(),
+        '~' => line.yellow().to_string(),
+        _ => line.dimmed().to_string(),
+    };
+}
+
+/// Compute the edit script between two sequences using a longest common subsequence
+fn diff_ops<E, A>(expected: &[E], actual: &[A]) -> Vec<DiffOp>
+where
+    E: PartialEq<A>,
+{
+    // Trim the common prefix and suffix so the LCS table only covers the changed middle
+    let prefix = expected.iter().zip(actual).take_while(|(e, a)| e == a).count();
+    let suffix = expected[prefix..].iter().rev().zip(actual[prefix..].iter().rev()).take_while(|(e, a)| e == a).count();
+
+    let expected_middle = &expected[prefix..expected.len() - suffix];
+    let actual_middle = &actual[prefix..actual.len() - suffix];
+
+    let mut ops: Vec<DiffOp> = (0..prefix).map(|i| DiffOp::Equal(i, i)).collect();
+
+    if expected_middle.len().saturating_mul(actual_middle.len()) > MAX_LCS_CELLS {
+        ops.extend((0..expected_middle.len()).map(|i| DiffOp::Delete(prefix + i)));
+        ops.extend((0..actual_middle.len()).map(|j| DiffOp::Insert(prefix + j)));
+    } else {
+        ops.extend(lcs_ops(expected_middle, actual_middle).into_iter().map(|op| match op {
+            DiffOp::Equal(i, j) => DiffOp::Equal(prefix + i, prefix + j),
+            DiffOp::Delete(i) => DiffOp::Delete(prefix + i),
+            DiffOp::Insert(j) => DiffOp::Insert(prefix + j),
+        }));
+    }
+
+    let expected_end = expected.len() - suffix;
+    let actual_end = actual.len() - suffix;
+    ops.extend((0..suffix).map(|k| DiffOp::Equal(expected_end + k, actual_end + k)));
+
+    return ops;
+}
+
+/// Classic dynamic-programming LCS, emitting deletions before insertions at each change
+fn lcs_ops<E, A>(expected: &[E], actual: &[A]) -> Vec<DiffOp>
+where
+    E: PartialEq<A>,
+{
+    let (n, m) = (expected.len(), actual.len());
+
+    // lengths[i][j] is the LCS length of expected[i..] and actual[j..]
+    let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i][j] = if expected[i] == actual[j] { lengths[i + 1][j + 1] + 1 } else { lengths[i + 1][j].max(lengths[i][j + 1]) };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if expected[i] == actual[j] {
+            ops.push(DiffOp::Equal(i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            ops.push(DiffOp::Delete(i));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Insert(j));
+            j += 1;
+        }
+    }
+    ops.extend((i..n).map(DiffOp::Delete));
+    ops.extend((j..m).map(DiffOp::Insert));
+
+    return ops;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        return items.iter().map(|item| item.to_string()).collect();
+    }
+
+    #[test]
+    fn test_text_diff_marks_changed_lines() {
+        let comparison = Comparison::Text { expected: "a\nb\nc".to_string(), actual: "a\nx\nc".to_string() };
+
+        let lines = render_diff(&comparison, false);
+
+        assert_eq!(lines, strings(&["Difference (- expected, + received):", "  a", "- b", "+ x", "  c"]));
+    }
+
+    #[test]
+    fn test_text_diff_collapses_distant_unchanged_lines() {
+        let expected = "1\n2\n3\n4\n5\n6\n7\n8";
+        let actual = "1\n2\n3\n4\n5\n6\n7\nX";
+        let comparison = Comparison::Text { expected: expected.to_string(), actual: actual.to_string() };
+
+        let lines = render_diff(&comparison, false);
+
+        assert_eq!(lines, strings(&["Difference (- expected, + received):", "  ...", "  6", "  7", "- 8", "+ X"]));
+    }
+
+    #[test]
+    fn test_sequence_diff_reports_changes_insertions_and_deletions() {
+        let changed = Comparison::Sequence { expected: strings(&["1", "2", "3"]), actual: strings(&["1", "5", "3"]) };
+        assert_eq!(render_diff(&changed, false)[1..], strings(&["~ [1] 2 → 5"]));
+
+        let missing = Comparison::Sequence { expected: strings(&["1", "2", "3"]), actual: strings(&["1", "3"]) };
+        assert_eq!(render_diff(&missing, false)[1..], strings(&["- [1] 2"]));
+
+        let unexpected = Comparison::Sequence { expected: strings(&["1", "3"]), actual: strings(&["1", "3", "4"]) };
+        assert_eq!(render_diff(&unexpected, false)[1..], strings(&["+ [2] 4"]));
+    }
+
+    #[test]
+    fn test_map_diff_reports_keys_sorted() {
+        let entry = |key: &str, value: &str| (key.to_string(), value.to_string());
+        let comparison = Comparison::Map {
+            expected: vec![entry("\"b\"", "2"), entry("\"a\"", "1"), entry("\"c\"", "3")],
+            actual: vec![entry("\"c\"", "3"), entry("\"b\"", "20"), entry("\"d\"", "4")],
+        };
+
+        let lines = render_diff(&comparison, false);
+
+        assert_eq!(lines[1..], strings(&["- \"a\": 1", "~ \"b\": 2 → 20", "+ \"d\": 4"]));
+    }
+
+    #[test]
+    fn test_map_diff_orders_numeric_keys_by_value() {
+        let entry = |key: &str, value: &str| (key.to_string(), value.to_string());
+        let comparison = Comparison::Map {
+            expected: vec![entry("10", "a"), entry("2", "b"), entry("$.items[10]", "1"), entry("$.items[2]", "2")],
+            actual: vec![],
+        };
+
+        let lines = render_diff(&comparison, false);
+
+        assert_eq!(lines[1..], strings(&["- 2: b", "- 10: a", "- $.items[2]: 2", "- $.items[10]: 1"]));
+    }
+
+    #[test]
+    fn test_large_inputs_fall_back_to_full_replacement() {
+        let expected: Vec<usize> = (0..1500).collect();
+        let actual: Vec<usize> = (0..1500).map(|n| n + 10_000).collect();
+
+        let ops = diff_ops(&expected, &actual);
+
+        assert_eq!(ops.len(), 3000);
+        assert!(ops[..1500].iter().all(|op| matches!(op, DiffOp::Delete(_))));
+        assert!(ops[1500..].iter().all(|op| matches!(op, DiffOp::Insert(_))));
+    }
+}
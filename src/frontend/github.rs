@@ -0,0 +1,118 @@
+//! GitHub Actions workflow commands, turning failures into annotations on the pull request
+
+use crate::backend::{Assertion, TestSessionResult};
+use crate::config::Config;
+use crate::frontend::ConsoleRenderer;
+
+/// Title of the log group holding the session summary
+const SUMMARY_GROUP: &str = "Rest test summary";
+
+/// Renders assertion results as GitHub Actions workflow commands
+pub struct GithubActionsRenderer {
+    console: ConsoleRenderer,
+}
+
+impl GithubActionsRenderer {
+    /// Create a new renderer; messages are rendered as on the console, without colors
+    pub fn new(config: Config) -> Self {
+        return Self { console: ConsoleRenderer::new(config.use_colors(false)) };
+    }
+
+    /// Render an `::error` command for a failed assertion, placed at its source location when it is known
+    pub fn render_failure_annotation(&self, result: &Assertion<()>) -> String {
+        let title = self.console.build_assertion_message(result);
+        let details = self.console.build_failure_details(result);
+
+        let mut properties = Vec::new();
+        if let Some(location) = &result.location {
+            properties.push(format!("file={}", escape_property(location.file)));
+            properties.push(format!("line={}", location.line));
+            properties.push(format!("col={}", location.column));
+        }
+        properties.push(format!("title={}", escape_property(&title)));
+
+        return format!("::error {}::{}", properties.join(","), escape_data(details.trim_end()));
+    }
+
+    /// Render the session summary in a collapsible log group
+    pub fn render_session_summary(&self, result: &TestSessionResult) -> String {
+        let summary = self.console.render_session_summary(result);
+
+        // A line starting with `::` inside the group would be read as a workflow command
+        let lines: Vec<String> =
+            summary.trim().lines().map(|line| if line.starts_with("::") { format!(" {}", line) } else { line.to_string() }).collect();
+
+        return format!("::group::{}\n{}\n::endgroup::", SUMMARY_GROUP, lines.join("\n"));
+    }
+}
+
+/// Escape the message of a workflow command
+fn escape_data(text: &str) -> String {
+    return text.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A");
+}
+
+/// Escape a property value of a workflow command
+fn escape_property(text: &str) -> String {
+    return escape_data(text).replace(':', "%3A").replace(',', "%2C");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::assertions::sentence::AssertionSentence;
+    use crate::backend::{AssertionStep, SourceLocation};
+
+    fn failure(location: Option<SourceLocation>) -> Assertion<()> {
+        let mut sentence = AssertionSentence::new("be", "equal to \"a, b: 100%\"");
+        sentence.subject = "label".to_string();
+
+        let mut assertion = Assertion::new((), "label");
+        assertion.steps.push(AssertionStep {
+            sentence,
+            passed: false,
+            logical_op: None,
+            actual: Some("\"a\"".to_string()),
+            received: None,
+            comparison: None,
+        });
+        assertion.location = location;
+        assertion.is_final = false;
+
+        return assertion;
+    }
+
+    #[test]
+    fn test_render_failure_annotation() {
+        let renderer = GithubActionsRenderer::new(Config::new());
+
+        let annotation = renderer.render_failure_annotation(&failure(Some(SourceLocation::new("tests/labels.rs", 12, 5))));
+
+        assert_eq!(
+            annotation,
+            concat!(
+                "::error file=tests/labels.rs,line=12,col=5,title=label is equal to \"a%2C b%3A 100%25\"::",
+                "  ✗ is equal to \"a, b: 100%25\"%0A      expected: be equal to \"a, b: 100%25\"%0A      received: \"a\"%0A",
+                "  at tests/labels.rs:12:5"
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_failure_annotation_without_location() {
+        let renderer = GithubActionsRenderer::new(Config::new());
+
+        let annotation = renderer.render_failure_annotation(&failure(None));
+
+        assert!(annotation.starts_with("::error title=label is equal to \"a%2C b%3A 100%25\"::"));
+    }
+
+    #[test]
+    fn test_render_session_summary_in_a_group() {
+        let renderer = GithubActionsRenderer::new(Config::new());
+        let session = TestSessionResult { passed_count: 2, ..Default::default() };
+
+        let summary = renderer.render_session_summary(&session);
+
+        assert_eq!(summary, "::group::Rest test summary\nTest Results:\n  2 passed / 0 failed\n::endgroup::");
+    }
+}
@@ -0,0 +1,283 @@
+//! JSON Lines event stream, for tools consuming assertion results as they happen
+//!
+//! Each line is a JSON object with `schema_version` set to [`EVENTS_SCHEMA_VERSION`] and an `event` of `success`,
+//! `failure` or `session_completed`. Fields are only ever added within a schema version, so consumers should ignore
+//! the ones they do not know.
+
+use crate::backend::{Assertion, LogicalOp};
+use crate::config::Config;
+use crate::frontend::ConsoleRenderer;
+use std::io::Write;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Version of the event records, raised when a field changes meaning or is removed
+pub const EVENTS_SCHEMA_VERSION: u32 = 1;
+
+/// Path standing for the standard output instead of a file
+pub const STDOUT_PATH: &str = "-";
+
+/// Renders assertion events as JSON Lines records
+pub struct JsonLinesRenderer {
+    console: ConsoleRenderer,
+}
+
+impl JsonLinesRenderer {
+    /// Create a new renderer; messages are rendered as on the console, without colors or symbols
+    pub fn new(config: Config) -> Self {
+        return Self { console: ConsoleRenderer::new(config.use_colors(false)) };
+    }
+
+    /// Render a success or failure event of the test running on `thread`
+    pub fn render_assertion(&self, result: &Assertion<()>, passed: bool, thread: Option<&str>, timestamp_ms: u128) -> String {
+        let subject = match result.steps.first() {
+            Some(step) if !step.sentence.subject.is_empty() => step.sentence.subject.as_str(),
+            _ => result.expr_str.trim_start_matches('&'),
+        };
+        // The main thread runs no test; every test runs on a thread named after it
+        let test = thread.filter(|name| *name != "main");
+
+        let steps: Vec<String> = result
+            .steps
+            .iter()
+            .map(|step| {
+                let logical_op = match step.logical_op {
+                    Some(LogicalOp::And) => json_string("and"),
+                    Some(LogicalOp::Or) => json_string("or"),
+                    None => "null".to_string(),
+                };
+                let qualifiers: Vec<String> = step.sentence.qualifiers.iter().map(|qualifier| json_string(qualifier)).collect();
+
+                return format!(
+                    "{{\"verb\":{},\"object\":{},\"qualifiers\":[{}],\"negated\":{},\"passed\":{},\"logical_op\":{}}}",
+                    json_string(&step.sentence.verb),
+                    json_string(&step.sentence.object),
+                    qualifiers.join(","),
+                    step.sentence.negated,
+                    step.passed,
+                    logical_op
+                );
+            })
+            .collect();
+
+        let location = match &result.location {
+            Some(location) => {
+                format!("{{\"file\":{},\"line\":{},\"column\":{}}}", json_string(location.file), location.line, location.column)
+            }
+            None => "null".to_string(),
+        };
+
+        return format!(
+            "{{\"schema_version\":{},\"event\":{},\"timestamp_ms\":{},\"thread\":{},\"test\":{},\"expression\":{},\"subject\":{},\"message\":{},\"location\":{},\"steps\":[{}]}}",
+            EVENTS_SCHEMA_VERSION,
+            json_string(if passed { "success" } else { "failure" }),
+            timestamp_ms,
+            json_optional(thread),
+            json_optional(test),
+            json_string(result.expr_str),
+            json_string(subject),
+            json_string(&self.console.build_assertion_message(result)),
+            location,
+            steps.join(",")
+        );
+    }
+
+    /// Render the record closing a session, with the number of events since the previous one
+    pub fn render_session_completed(&self, passed_count: usize, failed_count: usize, timestamp_ms: u128) -> String {
+        return format!(
+            "{{\"schema_version\":{},\"event\":\"session_completed\",\"timestamp_ms\":{},\"passed\":{},\"failed\":{}}}",
+            EVENTS_SCHEMA_VERSION, timestamp_ms, passed_count, failed_count
+        );
+    }
+}
+
+/// Writes event records to a file or to the standard output, counting them for the session record
+pub struct JsonLinesWriter {
+    output: Box<dyn Write + Send>,
+    passed_count: usize,
+    failed_count: usize,
+    pending: bool,
+}
+
+impl JsonLinesWriter {
+    /// Open the stream at `path`, truncating the file, or on the standard output for [`STDOUT_PATH`]
+    pub fn open(path: &Path) -> std::io::Result<Self> {
+        if path == Path::new(STDOUT_PATH) {
+            return Ok(Self::new(Box::new(std::io::stdout())));
+        }
+
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        return Ok(Self::new(Box::new(std::fs::File::create(path)?)));
+    }
+
+    /// Write the records to any output
+    pub fn new(output: Box<dyn Write + Send>) -> Self {
+        return Self { output, passed_count: 0, failed_count: 0, pending: false };
+    }
+
+    /// Write the record of an assertion result
+    pub fn write_assertion(&mut self, renderer: &JsonLinesRenderer, result: &Assertion<()>, passed: bool) -> std::io::Result<()> {
+        let thread = std::thread::current();
+        let line = renderer.render_assertion(result, passed, thread.name(), now_ms());
+
+        if passed {
+            self.passed_count += 1;
+        } else {
+            self.failed_count += 1;
+        }
+        self.pending = true;
+
+        return self.write_line(&line);
+    }
+
+    /// Write the session record, unless no assertion was recorded since the previous one
+    pub fn write_session_completed(&mut self, renderer: &JsonLinesRenderer) -> std::io::Result<()> {
+        if !self.pending {
+            return Ok(());
+        }
+
+        let line = renderer.render_session_completed(self.passed_count, self.failed_count, now_ms());
+        self.passed_count = 0;
+        self.failed_count = 0;
+        self.pending = false;
+
+        return self.write_line(&line);
+    }
+
+    /// Write a whole line at once, so records of concurrent tests never interleave
+    fn write_line(&mut self, line: &str) -> std::io::Result<()> {
+        self.output.write_all(format!("{}\n", line).as_bytes())?;
+        return self.output.flush();
+    }
+}
+
+/// Milliseconds since the Unix epoch
+fn now_ms() -> u128 {
+    return SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_millis()).unwrap_or(0);
+}
+
+/// A JSON string literal
+fn json_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    return escaped;
+}
+
+/// A JSON string literal, or `null`
+fn json_optional(text: Option<&str>) -> String {
+    return text.map(json_string).unwrap_or_else(|| "null".to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::assertions::sentence::AssertionSentence;
+    use crate::backend::{AssertionStep, SourceLocation};
+    use std::sync::{Arc, Mutex};
+
+    /// An output whose content the test can read back
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            return Ok(buf.len());
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            return Ok(());
+        }
+    }
+
+    fn assertion() -> Assertion<()> {
+        let step = |verb: &str, object: &str, passed: bool, logical_op: Option<LogicalOp>| {
+            let mut sentence = AssertionSentence::new(verb, object);
+            sentence.subject = "total".to_string();
+            return AssertionStep { sentence, passed, logical_op, actual: Some("5".to_string()), received: None, comparison: None };
+        };
+
+        let mut assertion = Assertion::new((), "&total");
+        assertion.steps.push(step("be", "greater than 3", true, Some(LogicalOp::And)));
+        assertion.steps.push(step("be", "less than \"4\"", false, None));
+        assertion.steps[1].sentence.negated = true;
+        assertion.location = Some(SourceLocation::new("tests/api.rs", 12, 5));
+        assertion.is_final = false;
+
+        return assertion;
+    }
+
+    #[test]
+    fn test_render_assertion() {
+        let renderer = JsonLinesRenderer::new(Config::new());
+
+        let line = renderer.render_assertion(&assertion(), false, Some("api::tests::test_total"), 1700000000000);
+
+        assert_eq!(
+            line,
+            concat!(
+                r#"{"schema_version":1,"event":"failure","timestamp_ms":1700000000000,"thread":"api::tests::test_total","#,
+                r#""test":"api::tests::test_total","expression":"&total","subject":"total","#,
+                r#""message":"total is greater than 3 AND is not less than \"4\"","#,
+                r#""location":{"file":"tests/api.rs","line":12,"column":5},"steps":["#,
+                r#"{"verb":"be","object":"greater than 3","qualifiers":[],"negated":false,"passed":true,"logical_op":"and"},"#,
+                r#"{"verb":"be","object":"less than \"4\"","qualifiers":[],"negated":true,"passed":false,"logical_op":null}]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_assertion_outside_a_test() {
+        let renderer = JsonLinesRenderer::new(Config::new());
+        let mut result = assertion();
+        result.location = None;
+
+        let line = renderer.render_assertion(&result, true, Some("main"), 0);
+
+        assert!(line.contains(r#""event":"success","timestamp_ms":0,"thread":"main","test":null,"#));
+        assert!(line.contains(r#""location":null,"#));
+    }
+
+    #[test]
+    fn test_writer_closes_sessions_with_their_counts() {
+        let buffer = SharedBuffer::default();
+        let renderer = JsonLinesRenderer::new(Config::new());
+        let mut writer = JsonLinesWriter::new(Box::new(buffer.clone()));
+
+        writer.write_assertion(&renderer, &assertion(), true).unwrap();
+        writer.write_assertion(&renderer, &assertion(), false).unwrap();
+        writer.write_session_completed(&renderer).unwrap();
+        writer.write_session_completed(&renderer).unwrap();
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains(r#""event":"success""#));
+        assert!(lines[1].contains(r#""event":"failure""#));
+        assert!(lines[2].starts_with(r#"{"schema_version":1,"event":"session_completed","timestamp_ms":"#));
+        assert!(lines[2].ends_with(r#","passed":1,"failed":1}"#));
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("say \"hi\"\\\n\t\u{1}é"), r#""say \"hi\"\\\n\t\u0001é""#);
+    }
+}
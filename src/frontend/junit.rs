@@ -0,0 +1,251 @@
+//! JUnit XML reports, for CI systems that read test results from a file
+
+use crate::backend::{Assertion, SourceLocation};
+use crate::config::Config;
+use crate::frontend::ConsoleRenderer;
+use std::path::Path;
+
+/// Assertion results of one test, named after the thread that ran it
+#[derive(Debug, Clone)]
+pub struct JunitTestCase {
+    /// Module path of the test, e.g. `api::tests`
+    pub classname: String,
+    /// Name of the test function
+    pub name: String,
+    /// Number of assertions evaluated by the test
+    pub assertions: usize,
+    /// Where the first assertion of the test was written
+    pub location: Option<SourceLocation>,
+    /// Failed assertions, in the order they were reported
+    pub failures: Vec<Assertion<()>>,
+}
+
+/// Assertion results collected from `AssertionEvent`s, grouped by test in the order tests were first seen
+#[derive(Debug, Clone, Default)]
+pub struct JunitReport {
+    pub cases: Vec<JunitTestCase>,
+}
+
+impl JunitReport {
+    /// Record an assertion result for the test `test`, e.g. `api::tests::test_login`
+    pub fn record(&mut self, test: &str, result: &Assertion<()>, passed: bool) {
+        let (classname, name) = test.rsplit_once("::").unwrap_or(("", test));
+
+        let index = match self.cases.iter().position(|case| case.classname == classname && case.name == name) {
+            Some(index) => index,
+            None => {
+                self.cases.push(JunitTestCase {
+                    classname: classname.to_string(),
+                    name: name.to_string(),
+                    assertions: 0,
+                    location: None,
+                    failures: Vec::new(),
+                });
+                self.cases.len() - 1
+            }
+        };
+
+        let case = &mut self.cases[index];
+        case.assertions += 1;
+        case.location = case.location.or(result.location);
+        if !passed {
+            // A stored copy must not be evaluated again when the report drops it
+            let mut failure = result.clone();
+            failure.mark_as_intermediate();
+            case.failures.push(failure);
+        }
+    }
+
+    /// Number of tests with at least one failed assertion
+    pub fn failed_count(&self) -> usize {
+        return self.cases.iter().filter(|case| !case.failures.is_empty()).count();
+    }
+}
+
+/// Renders collected assertion results as JUnit XML
+pub struct JunitRenderer {
+    console: ConsoleRenderer,
+}
+
+impl JunitRenderer {
+    /// Create a new renderer; failures are described as on the console, without colors
+    pub fn new(config: Config) -> Self {
+        return Self { console: ConsoleRenderer::new(config.use_colors(false)) };
+    }
+
+    /// Render the report as a `<testsuites>` document with a single `<testsuite>`
+    pub fn render_report(&self, report: &JunitReport, suite: &str) -> String {
+        let tests = report.cases.len();
+        let failures = report.failed_count();
+
+        let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        output.push_str(&format!("<testsuites tests=\"{}\" failures=\"{}\" errors=\"0\">\n", tests, failures));
+        output.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\">\n",
+            escape_xml(suite),
+            tests,
+            failures
+        ));
+
+        for case in &report.cases {
+            // Tests at the root of a test crate are classed under the suite
+            let classname = if case.classname.is_empty() { suite } else { case.classname.as_str() };
+            output.push_str(&format!("    <testcase classname=\"{}\" name=\"{}\"", escape_xml(classname), escape_xml(&case.name)));
+            if let Some(location) = &case.location {
+                output.push_str(&format!(" file=\"{}\" line=\"{}\"", escape_xml(location.file), location.line));
+            }
+            output.push_str(&format!(" assertions=\"{}\"", case.assertions));
+
+            if case.failures.is_empty() {
+                output.push_str("/>\n");
+                continue;
+            }
+
+            output.push_str(">\n");
+            output.push_str(&self.render_failure(&case.failures));
+            output.push_str("    </testcase>\n");
+        }
+
+        output.push_str("  </testsuite>\n</testsuites>\n");
+
+        return output;
+    }
+
+    /// Render the failed assertions of a test as one `<failure>` element, as most CI systems show only one
+    fn render_failure(&self, failures: &[Assertion<()>]) -> String {
+        let (header, _) = self.console.render_failure(&failures[0]);
+        let message = match failures.len() {
+            1 => header,
+            count => format!("{} assertions failed, first: {}", count, header),
+        };
+
+        let body: Vec<String> = failures
+            .iter()
+            .map(|failure| {
+                let (header, details) = self.console.render_failure(failure);
+                return format!("{}\n{}", header, details);
+            })
+            .collect();
+
+        return format!(
+            "      <failure message=\"{}\" type=\"AssertionFailure\">{}</failure>\n",
+            escape_xml(&message),
+            escape_xml(body.join("\n").trim_end())
+        );
+    }
+
+    /// Write the report to `path`, creating its directory if needed
+    pub fn write_report(&self, report: &JunitReport, suite: &str, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        return std::fs::write(path, self.render_report(report, suite));
+    }
+}
+
+/// Escape text for XML attributes and content, dropping the control characters XML 1.0 does not allow
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' | '\r' | '\t' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+
+    return escaped;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::AssertionStep;
+    use crate::backend::assertions::sentence::AssertionSentence;
+
+    fn assertion(passed: bool, location: Option<SourceLocation>) -> Assertion<()> {
+        let mut sentence = AssertionSentence::new("be", "less than <3>");
+        sentence.subject = "total".to_string();
+
+        let mut assertion = Assertion::new((), "total");
+        assertion.steps.push(AssertionStep {
+            sentence,
+            passed,
+            logical_op: None,
+            actual: Some("5".to_string()),
+            received: None,
+            comparison: None,
+        });
+        assertion.location = location;
+        assertion.is_final = false;
+
+        return assertion;
+    }
+
+    #[test]
+    fn test_record_groups_results_by_test() {
+        let location = SourceLocation::new("tests/api.rs", 12, 5);
+        let mut report = JunitReport::default();
+
+        report.record("api::tests::test_total", &assertion(true, Some(location)), true);
+        report.record("api::tests::test_total", &assertion(false, None), false);
+        report.record("main", &assertion(true, None), true);
+
+        assert_eq!(report.cases.len(), 2);
+        assert_eq!((report.cases[0].classname.as_str(), report.cases[0].name.as_str()), ("api::tests", "test_total"));
+        assert_eq!(report.cases[0].assertions, 2);
+        assert_eq!(report.cases[0].location, Some(location));
+        assert_eq!(report.cases[0].failures.len(), 1);
+        assert_eq!((report.cases[1].classname.as_str(), report.cases[1].name.as_str()), ("", "main"));
+        assert_eq!(report.failed_count(), 1);
+    }
+
+    #[test]
+    fn test_render_report() {
+        let mut report = JunitReport::default();
+        report.record("test_ok", &assertion(true, None), true);
+        report.record("api::tests::test_total", &assertion(false, Some(SourceLocation::new("tests/api.rs", 12, 5))), false);
+
+        let xml = JunitRenderer::new(Config::new()).render_report(&report, "api");
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites tests=\"2\" failures=\"1\" errors=\"0\">\n"));
+        assert!(xml.contains("  <testsuite name=\"api\" tests=\"2\" failures=\"1\" errors=\"0\" skipped=\"0\">\n"));
+        assert!(xml.contains("    <testcase classname=\"api\" name=\"test_ok\" assertions=\"1\"/>\n"));
+        assert!(
+            xml.contains(
+                "    <testcase classname=\"api::tests\" name=\"test_total\" file=\"tests/api.rs\" line=\"12\" assertions=\"1\">\n"
+            )
+        );
+        assert!(xml.contains(
+            "      <failure message=\"✗ total is less than &lt;3&gt;\" type=\"AssertionFailure\">✗ total is less than &lt;3&gt;\n"
+        ));
+        assert!(
+            xml.contains("      expected: be less than &lt;3&gt;\n      received: 5\n  at tests/api.rs:12:5</failure>\n    </testcase>\n")
+        );
+        assert!(xml.ends_with("  </testsuite>\n</testsuites>\n"));
+    }
+
+    #[test]
+    fn test_render_several_failures_of_a_test() {
+        let mut report = JunitReport::default();
+        report.record("test_total", &assertion(false, None), false);
+        report.record("test_total", &assertion(false, None), false);
+
+        let xml = JunitRenderer::new(Config::new()).render_report(&report, "api");
+
+        assert!(xml.contains("<failure message=\"2 assertions failed, first: ✗ total is less than &lt;3&gt;\""));
+        assert_eq!(xml.matches("received: 5").count(), 2);
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml("a < b && \"c\" > 'd'\n\u{1b}[31m"), "a &lt; b &amp;&amp; &quot;c&quot; &gt; &apos;d&apos;\n[31m");
+    }
+}
@@ -1,6 +1,17 @@
 //! Frontend module for rendering test results
 
 mod console;
+mod diff;
+mod github;
+mod json_lines;
+mod junit;
+mod tap;
 
 pub use crate::backend::{Assertion, AssertionStep, TestSessionResult};
 pub use console::ConsoleRenderer;
+pub use github::GithubActionsRenderer;
+pub use json_lines::{EVENTS_SCHEMA_VERSION, JsonLinesRenderer, JsonLinesWriter, STDOUT_PATH};
+pub use junit::{JunitRenderer, JunitReport, JunitTestCase};
+pub use tap::{TAP_VERSION_LINE, TapRenderer, TapWriter};
+
+pub(crate) use diff::render_diff;
@@ -0,0 +1,209 @@
+//! TAP (Test Anything Protocol) output, one test point per assertion
+
+use crate::backend::Assertion;
+use crate::config::Config;
+use crate::frontend::ConsoleRenderer;
+use std::io::Write;
+
+/// First line of a TAP stream
+pub const TAP_VERSION_LINE: &str = "TAP version 14";
+
+/// Renders assertion results as TAP 14 test points
+pub struct TapRenderer {
+    console: ConsoleRenderer,
+}
+
+impl TapRenderer {
+    /// Create a new renderer; messages are rendered as on the console, without colors
+    pub fn new(config: Config) -> Self {
+        return Self { console: ConsoleRenderer::new(config.use_colors(false)) };
+    }
+
+    /// Render the test point `number`, with a YAML diagnostic block for a failure
+    pub fn render_result(&self, number: usize, result: &Assertion<()>, passed: bool) -> String {
+        // `#` starts a directive in a description, so it is escaped
+        let description = self.console.build_assertion_message(result).replace('\\', "\\\\").replace('#', "\\#");
+
+        if passed {
+            return format!("ok {} - {}", number, description);
+        }
+
+        let mut output = format!("not ok {} - {}\n  ---\n  message: |-\n", number, description);
+        for line in self.console.build_failure_details(result).lines() {
+            output.push_str(&format!("    {}\n", line));
+        }
+        output.push_str("  severity: fail\n");
+
+        if let Some(location) = &result.location {
+            output.push_str(&format!(
+                "  at:\n    file: {}\n    line: {}\n    column: {}\n",
+                yaml_string(location.file),
+                location.line,
+                location.column
+            ));
+        }
+        output.push_str("  ...");
+
+        return output;
+    }
+
+    /// Render the plan line closing a stream of `count` test points
+    pub fn render_plan(&self, count: usize) -> String {
+        return format!("1..{}", count);
+    }
+}
+
+/// Writes TAP streams, numbering the test points of every thread in order
+pub struct TapWriter {
+    output: Box<dyn Write + Send>,
+    count: usize,
+}
+
+impl TapWriter {
+    /// Write the streams to any output
+    pub fn new(output: Box<dyn Write + Send>) -> Self {
+        return Self { output, count: 0 };
+    }
+
+    /// Write the next test point, starting a new stream with the version line if needed
+    pub fn write_result(&mut self, renderer: &TapRenderer, result: &Assertion<()>, passed: bool) -> std::io::Result<()> {
+        let mut lines = String::new();
+        if self.count == 0 {
+            lines.push_str(TAP_VERSION_LINE);
+            lines.push('\n');
+        }
+
+        self.count += 1;
+        lines.push_str(&renderer.render_result(self.count, result, passed));
+        lines.push('\n');
+
+        return self.write(&lines);
+    }
+
+    /// Close the current stream with its plan, unless it has no test point yet
+    pub fn write_plan(&mut self, renderer: &TapRenderer) -> std::io::Result<()> {
+        if self.count == 0 {
+            return Ok(());
+        }
+
+        let plan = format!("{}\n", renderer.render_plan(self.count));
+        self.count = 0;
+
+        return self.write(&plan);
+    }
+
+    /// Write whole test points at once, so those of concurrent tests never interleave
+    fn write(&mut self, text: &str) -> std::io::Result<()> {
+        self.output.write_all(text.as_bytes())?;
+        return self.output.flush();
+    }
+}
+
+/// A double-quoted YAML string
+fn yaml_string(text: &str) -> String {
+    return format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::assertions::sentence::AssertionSentence;
+    use crate::backend::{AssertionStep, SourceLocation};
+    use std::sync::{Arc, Mutex};
+
+    /// An output whose content the test can read back
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            return Ok(buf.len());
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            return Ok(());
+        }
+    }
+
+    fn assertion(object: &str, passed: bool) -> Assertion<()> {
+        let mut sentence = AssertionSentence::new("be", object);
+        sentence.subject = "total".to_string();
+
+        let mut assertion = Assertion::new((), "total");
+        assertion.steps.push(AssertionStep {
+            sentence,
+            passed,
+            logical_op: None,
+            actual: Some("5".to_string()),
+            received: None,
+            comparison: None,
+        });
+        assertion.location = Some(SourceLocation::new("tests/api.rs", 12, 5));
+        assertion.is_final = false;
+
+        return assertion;
+    }
+
+    #[test]
+    fn test_render_passed_result() {
+        let renderer = TapRenderer::new(Config::new());
+
+        assert_eq!(renderer.render_result(1, &assertion("greater than 3", true), true), "ok 1 - total is greater than 3");
+        assert_eq!(renderer.render_result(2, &assertion("tagged #1", true), true), "ok 2 - total is tagged \\#1");
+    }
+
+    #[test]
+    fn test_render_failed_result_with_diagnostics() {
+        let renderer = TapRenderer::new(Config::new());
+
+        let output = renderer.render_result(3, &assertion("less than 3", false), false);
+
+        assert_eq!(
+            output,
+            concat!(
+                "not ok 3 - total is less than 3\n",
+                "  ---\n",
+                "  message: |-\n",
+                "      ✗ is less than 3\n",
+                "          expected: be less than 3\n",
+                "          received: 5\n",
+                "      at tests/api.rs:12:5\n",
+                "  severity: fail\n",
+                "  at:\n",
+                "    file: \"tests/api.rs\"\n",
+                "    line: 12\n",
+                "    column: 5\n",
+                "  ..."
+            )
+        );
+    }
+
+    #[test]
+    fn test_writer_numbers_results_and_closes_with_a_plan() {
+        let buffer = SharedBuffer::default();
+        let renderer = TapRenderer::new(Config::new());
+        let mut writer = TapWriter::new(Box::new(buffer.clone()));
+
+        writer.write_plan(&renderer).unwrap();
+        writer.write_result(&renderer, &assertion("greater than 3", true), true).unwrap();
+        writer.write_result(&renderer, &assertion("less than 3", false), false).unwrap();
+        writer.write_plan(&renderer).unwrap();
+        writer.write_result(&renderer, &assertion("greater than 3", true), true).unwrap();
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<&str> = output.lines().filter(|line| !line.starts_with(' ')).collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "TAP version 14",
+                "ok 1 - total is greater than 3",
+                "not ok 2 - total is less than 3",
+                "1..2",
+                "TAP version 14",
+                "ok 1 - total is greater than 3"
+            ]
+        );
+    }
+}
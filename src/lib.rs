@@ -40,9 +40,10 @@ mod reporter;
 // Auto-initialize for tests if enhanced output is enabled
 pub fn auto_initialize_for_tests() {
     TEST_INIT.call_once(|| {
-        // Check environment variable to enable enhanced output
+        // Check environment variables to enable enhanced output or a report
+        // A configuration applied before the first assertion already includes them
         let config = config::Config::new();
-        if config.enhanced_output {
+        if config.emits_events() && !config::is_applied() {
             // Apply the config which will initialize the event system
             config.apply();
         }
@@ -53,7 +54,7 @@ pub fn auto_initialize_for_tests() {
 pub use config::initialize;
 
 // Export attribute macros for fixtures
-pub use rest_macros::{after_all, before_all, setup, tear_down, with_fixtures, with_fixtures_module};
+pub use rest_macros::{after_all, before_all, setup, soft, tear_down, with_fixtures, with_fixtures_module};
 
 // Global exit handler for after_all fixtures
 #[ctor::dtor]
@@ -61,16 +62,41 @@ fn run_after_all_fixtures() {
     backend::fixtures::run_after_all_fixtures();
 }
 
+// Write the report files at exit, for test binaries that never call `Reporter::summarize`
+#[ctor::dtor]
+fn write_reports() {
+    Reporter::write_reports();
+}
+
+// Remove obsolete snapshot entries at exit, once every test of the binary has run
+#[ctor::dtor]
+fn remove_obsolete_snapshots() {
+    Reporter::remove_obsolete_snapshots();
+}
+
 /// Matcher traits module for bringing the traits into scope
 pub mod matchers {
     pub use crate::backend::matchers::boolean::BooleanMatchers;
-    pub use crate::backend::matchers::collection::{CollectionExtensions, CollectionMatchers};
+    pub use crate::backend::matchers::collection::{CollectModifier, CollectionExtensions, CollectionMatchers};
     pub use crate::backend::matchers::equality::EqualityMatchers;
+    pub use crate::backend::matchers::float::{FloatCollectionMatchers, FloatMatchers};
     pub use crate::backend::matchers::hashmap::HashMapMatchers;
+    #[cfg(feature = "json")]
+    pub use crate::backend::matchers::json::{AsJson, JsonMatchers};
+    pub use crate::backend::matchers::matcher::{MatchResult, Matcher, SatisfyMatchers};
     pub use crate::backend::matchers::numeric::NumericMatchers;
     pub use crate::backend::matchers::option::OptionMatchers;
+    pub use crate::backend::matchers::panic::PanicMatchers;
     pub use crate::backend::matchers::result::ResultMatchers;
+    pub use crate::backend::matchers::snapshot::SnapshotMatchers;
     pub use crate::backend::matchers::string::StringMatchers;
+
+    /// Constructors of composable matchers, kept out of the prelude so they don't clash with user items
+    pub mod matcher {
+        pub use crate::backend::matchers::matcher::{
+            all_of, any_of, contains, each, eq, err, ge, gt, le, lt, ne, none, none_of, not, ok, predicate, some,
+        };
+    }
 }
 
 /// Main prelude module containing everything needed for fluent testing
@@ -82,6 +108,10 @@ pub mod prelude {
     // Fixture attribute macros
     pub use crate::{after_all, before_all, setup, tear_down, with_fixtures, with_fixtures_module};
 
+    // Soft assertions
+    pub use crate::backend::soft_assertions;
+    pub use crate::soft;
+
     // Import all matcher traits
     pub use crate::matchers::*;
 
@@ -97,7 +127,7 @@ pub mod prelude {
 }
 
 // Re-exports
-pub use crate::config::Config;
+pub use crate::config::{Config, OutputFormat};
 pub use crate::reporter::Reporter;
 
 /// Creates a new test configuration
@@ -112,7 +142,11 @@ macro_rules! expect {
         // Always auto-initialize
         $crate::auto_initialize_for_tests();
 
-        $crate::backend::Assertion::new($expr, stringify!($expr))
+        $crate::backend::Assertion::new($expr, stringify!($expr)).with_location($crate::backend::SourceLocation::new(
+            file!(),
+            line!(),
+            column!(),
+        ))
     }};
 }
 
@@ -125,7 +159,9 @@ macro_rules! expect_not {
         $crate::auto_initialize_for_tests();
 
         use $crate::backend::modifiers::NotModifier;
-        $crate::backend::Assertion::new($expr, stringify!($expr)).not()
+        $crate::backend::Assertion::new($expr, stringify!($expr))
+            .with_location($crate::backend::SourceLocation::new(file!(), line!(), column!()))
+            .not()
     }};
 }
 
@@ -167,12 +203,18 @@ macro_rules! rest_test {
 pub mod test_utils {
     // Just re-export all the traits for easy importing in tests
     pub use crate::backend::matchers::boolean::BooleanMatchers;
-    pub use crate::backend::matchers::collection::{CollectionExtensions, CollectionMatchers};
+    pub use crate::backend::matchers::collection::{CollectModifier, CollectionExtensions, CollectionMatchers};
     pub use crate::backend::matchers::equality::EqualityMatchers;
+    pub use crate::backend::matchers::float::{FloatCollectionMatchers, FloatMatchers};
     pub use crate::backend::matchers::hashmap::HashMapMatchers;
+    #[cfg(feature = "json")]
+    pub use crate::backend::matchers::json::{AsJson, JsonMatchers};
+    pub use crate::backend::matchers::matcher::{MatchResult, Matcher, SatisfyMatchers};
     pub use crate::backend::matchers::numeric::NumericMatchers;
     pub use crate::backend::matchers::option::OptionMatchers;
+    pub use crate::backend::matchers::panic::PanicMatchers;
     pub use crate::backend::matchers::result::ResultMatchers;
+    pub use crate::backend::matchers::snapshot::SnapshotMatchers;
     pub use crate::backend::matchers::string::StringMatchers;
 
     // Helper function to set up testing
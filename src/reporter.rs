@@ -1,14 +1,36 @@
 use crate::backend::{Assertion, TestSessionResult};
-use crate::config::Config;
+use crate::config::{Config, OutputFormat};
 use crate::events::{AssertionEvent, EventEmitter, on_failure, on_success};
-use crate::frontend::ConsoleRenderer;
+use crate::frontend::{
+    ConsoleRenderer, GithubActionsRenderer, JsonLinesRenderer, JsonLinesWriter, JunitRenderer, JunitReport, TapRenderer, TapWriter,
+};
 use once_cell::sync::Lazy;
 use std::cell::RefCell;
 use std::collections::HashSet;
-use std::sync::RwLock;
+use std::sync::{Mutex, RwLock};
 
 pub(crate) static GLOBAL_CONFIG: Lazy<RwLock<Config>> = Lazy::new(|| RwLock::new(Config::new()));
 
+// The JUnit report covers every test of the process, whatever thread ran it
+static JUNIT_REPORT: Lazy<Mutex<JunitReport>> = Lazy::new(|| Mutex::new(JunitReport::default()));
+
+// The JSON Lines stream, opened on the first event; `None` inside when it cannot be opened
+static EVENT_STREAM: Lazy<Mutex<Option<JsonLinesWriter>>> = Lazy::new(|| {
+    let path = GLOBAL_CONFIG.read().unwrap_or_else(|poisoned| poisoned.into_inner()).events_output.clone();
+    let writer = path.and_then(|path| match JsonLinesWriter::open(&path) {
+        Ok(writer) => Some(writer),
+        Err(err) => {
+            eprintln!("rest: cannot write the event stream to {}: {}", path.display(), err);
+            None
+        }
+    });
+
+    return Mutex::new(writer);
+});
+
+// TAP test points are numbered across threads, and written past the test harness' output capture for TAP consumers
+static TAP_STREAM: Lazy<Mutex<TapWriter>> = Lazy::new(|| Mutex::new(TapWriter::new(Box::new(std::io::stdout()))));
+
 thread_local! {
     static TEST_SESSION: RefCell<TestSessionResult> = RefCell::new(TestSessionResult::default());
     // Track already reported messages to avoid duplicates
@@ -41,6 +63,7 @@ impl Reporter {
             let mut session = session.borrow_mut();
             session.passed_count += 1;
         });
+        Self::record_for_reports(&result, true);
 
         // Check if silent mode is enabled
         let silent = SILENT_MODE.with(|silent| *silent.borrow());
@@ -68,14 +91,11 @@ impl Reporter {
             })
         });
 
-        if should_report {
-            let config = GLOBAL_CONFIG.read().unwrap();
-            let renderer = ConsoleRenderer::new(Config {
-                use_colors: config.use_colors,
-                use_unicode_symbols: config.use_unicode_symbols,
-                show_success_details: config.show_success_details,
-                enhanced_output: config.enhanced_output,
-            });
+        let config = GLOBAL_CONFIG.read().unwrap();
+        if should_report && config.output_format == OutputFormat::Tap {
+            Self::print_tap_result(&config, &result, true);
+        } else if should_report && config.enhanced_output {
+            let renderer = ConsoleRenderer::new(config.clone());
             renderer.print_success(&result);
         }
     }
@@ -87,6 +107,7 @@ impl Reporter {
             session.failed_count += 1;
             session.failures.push(result.clone());
         });
+        Self::record_for_reports(&result, false);
 
         // Check if silent mode is enabled
         let silent = SILENT_MODE.with(|silent| *silent.borrow());
@@ -114,16 +135,100 @@ impl Reporter {
             })
         });
 
-        if should_report {
-            let config = GLOBAL_CONFIG.read().unwrap();
-            let renderer = ConsoleRenderer::new(Config {
-                use_colors: config.use_colors,
-                use_unicode_symbols: config.use_unicode_symbols,
-                show_success_details: config.show_success_details,
-                enhanced_output: config.enhanced_output,
-            });
+        let config = GLOBAL_CONFIG.read().unwrap();
+        if should_report && config.output_format == OutputFormat::Tap {
+            Self::print_tap_result(&config, &result, false);
+        } else if should_report && config.enhanced_output {
+            let renderer = ConsoleRenderer::new(config.clone());
             renderer.print_failure(&result);
         }
+
+        if should_report && config.github_annotations {
+            let renderer = GithubActionsRenderer::new(config.clone());
+            println!("{}", renderer.render_failure_annotation(&result));
+        }
+    }
+
+    /// Print the next TAP test point
+    fn print_tap_result(config: &Config, result: &Assertion<()>, passed: bool) {
+        let renderer = TapRenderer::new(config.clone());
+        if let Err(err) = TAP_STREAM.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).write_result(&renderer, result, passed) {
+            eprintln!("rest: cannot write the TAP output: {}", err);
+        }
+    }
+
+    /// Print the plan closing the current TAP stream
+    fn print_tap_plan(config: &Config) {
+        let renderer = TapRenderer::new(config.clone());
+        if let Err(err) = TAP_STREAM.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).write_plan(&renderer) {
+            eprintln!("rest: cannot write the TAP output: {}", err);
+        }
+    }
+
+    /// Collect an assertion result for the configured report files, under the name of the test thread
+    fn record_for_reports(result: &Assertion<()>, passed: bool) {
+        let config = GLOBAL_CONFIG.read().unwrap().clone();
+
+        if config.junit_output.is_some() {
+            let thread = std::thread::current();
+            let test = thread.name().unwrap_or("main");
+            JUNIT_REPORT.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).record(test, result, passed);
+        }
+
+        if config.events_output.is_some()
+            && let Some(writer) = EVENT_STREAM.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).as_mut()
+            && let Err(err) = writer.write_assertion(&JsonLinesRenderer::new(config), result, passed)
+        {
+            eprintln!("rest: cannot write to the event stream: {}", err);
+        }
+    }
+
+    /// Write the configured report files with every result collected so far, and close the TAP and event streams
+    ///
+    /// Called by `summarize` and again when the process ends, so the files are complete even without a summary.
+    pub(crate) fn write_reports() {
+        let config = GLOBAL_CONFIG.read().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+
+        if config.output_format == OutputFormat::Tap {
+            Self::print_tap_plan(&config);
+        }
+
+        if let Some(path) = &config.junit_output {
+            let report = JUNIT_REPORT.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+            let suite = std::env::var("CARGO_PKG_NAME").unwrap_or_else(|_| "rest".to_string());
+
+            if let Err(err) = JunitRenderer::new(config.clone()).write_report(&report, &suite, path) {
+                eprintln!("rest: cannot write the JUnit report to {}: {}", path.display(), err);
+            }
+        }
+
+        if config.events_output.is_some()
+            && let Some(writer) = EVENT_STREAM.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).as_mut()
+            && let Err(err) = writer.write_session_completed(&JsonLinesRenderer::new(config.clone()))
+        {
+            eprintln!("rest: cannot write to the event stream: {}", err);
+        }
+    }
+
+    /// Remove the obsolete snapshot entries when enabled, once every test of the binary has run
+    ///
+    /// Called when the process ends. A filtered run leaves tests out, whose entries would look obsolete, so it keeps
+    /// every entry and says so.
+    pub(crate) fn remove_obsolete_snapshots() {
+        let config = GLOBAL_CONFIG.read().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+        if !config.remove_obsolete_snapshots || crate::backend::snapshot::obsolete_entries().is_empty() {
+            return;
+        }
+
+        if crate::backend::snapshot::is_filtered_run(std::env::args().skip(1)) {
+            eprintln!("rest: obsolete snapshots are kept, as they are only removed when the whole test suite runs");
+            return;
+        }
+
+        println!("\nRemoved Obsolete Snapshots:");
+        for entry in crate::backend::snapshot::remove_obsolete_entries() {
+            println!("  {}", entry);
+        }
     }
 
     /// Clear the message cache to allow duplicated messages in different test scopes
@@ -163,20 +268,31 @@ impl Reporter {
 
     pub fn summarize() {
         TEST_SESSION.with(|session| {
-            let session = session.borrow();
+            let mut session = session.borrow_mut();
             let config = GLOBAL_CONFIG.read().unwrap();
-            let renderer = ConsoleRenderer::new(Config {
-                use_colors: config.use_colors,
-                use_unicode_symbols: config.use_unicode_symbols,
-                show_success_details: config.show_success_details,
-                enhanced_output: config.enhanced_output,
-            });
-            renderer.print_session_summary(&session);
+
+            // Only reported here: tests that have not run yet, or were filtered out, look obsolete too
+            session.obsolete_snapshots = crate::backend::snapshot::obsolete_entries();
+
+            // The TAP stream is closed by its plan instead, written with the reports
+            if config.output_format == OutputFormat::Tap {
+                return;
+            }
+
+            if config.github_annotations {
+                let renderer = GithubActionsRenderer::new(config.clone());
+                println!("{}", renderer.render_session_summary(&session));
+            } else {
+                let renderer = ConsoleRenderer::new(config.clone());
+                renderer.print_session_summary(&session);
+            }
         });
 
         // Emit session completed event
         EventEmitter::emit(AssertionEvent::SessionCompleted);
 
+        Self::write_reports();
+
         // Clear reported messages
         Self::reset_message_cache();
 
@@ -201,6 +317,9 @@ mod tests {
             sentence: AssertionSentence::new("be", if passed { "correct" } else { "incorrect" }),
             passed,
             logical_op: None,
+            actual: None,
+            received: None,
+            comparison: None,
         });
 
         // Set it as non-final to prevent Drop evaluation
@@ -219,17 +338,33 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_stored_failures_are_not_evaluated_again() {
+        EventEmitter::init();
+        Reporter::init();
+
+        // A final failure, as reported while the assertion is evaluated
+        let mut assertion = create_test_assertion(false);
+        assertion.is_final = true;
+        EventEmitter::emit(AssertionEvent::Failure(assertion));
+
+        // The session drops its failures when its thread ends, when evaluating them again would report them again
+        let failures = TEST_SESSION.with(|session| std::mem::take(&mut session.borrow_mut().failures));
+        assert_eq!(failures.len(), 1);
+        assert!(!failures[0].is_final);
+    }
+
     #[test]
     fn test_reporter_deduplication_flags() {
         // Test enabling and disabling deduplication
         Reporter::enable_deduplication();
         DEDUPLICATE_ENABLED.with(|enabled| {
-            assert_eq!(*enabled.borrow(), true);
+            assert!(*enabled.borrow());
         });
 
         Reporter::disable_deduplication();
         DEDUPLICATE_ENABLED.with(|enabled| {
-            assert_eq!(*enabled.borrow(), false);
+            assert!(!(*enabled.borrow()));
         });
 
         // Reset to default state
@@ -241,12 +376,12 @@ mod tests {
         // Test enabling and disabling silent mode
         Reporter::enable_silent_mode();
         SILENT_MODE.with(|silent| {
-            assert_eq!(*silent.borrow(), true);
+            assert!(*silent.borrow());
         });
 
         Reporter::disable_silent_mode();
         SILENT_MODE.with(|silent| {
-            assert_eq!(*silent.borrow(), false);
+            assert!(!(*silent.borrow()));
         });
     }
 
@@ -340,7 +475,7 @@ mod tests {
 
         // Verify silent mode is enabled
         SILENT_MODE.with(|silent| {
-            assert_eq!(*silent.borrow(), true);
+            assert!(*silent.borrow());
         });
 
         // Test that success events still increment the counter in silent mode
@@ -363,7 +498,7 @@ mod tests {
 
         // Verify silent mode is disabled
         SILENT_MODE.with(|silent| {
-            assert_eq!(*silent.borrow(), false);
+            assert!(!(*silent.borrow()));
         });
 
         // Clean up
@@ -0,0 +1,19 @@
+use rest::prelude::*;
+
+#[test]
+fn test_applied_config_is_kept_by_the_first_assertion() {
+    let dir = std::env::temp_dir().join(format!("rest-config-{}", std::process::id()));
+    let applied = dir.join("applied.jsonl");
+    let from_env = dir.join("from_env.jsonl");
+
+    // SAFETY: the only test of this binary, so no other thread reads the environment meanwhile
+    unsafe { std::env::set_var("REST_EVENTS_OUTPUT", &from_env) };
+
+    rest::config().events_output(&applied).apply();
+
+    expect!(2 + 2).to_equal(4);
+    rest::Reporter::summarize();
+
+    expect!(applied.exists()).to_be_true();
+    expect!(from_env.exists()).to_be_false();
+}
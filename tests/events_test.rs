@@ -0,0 +1,32 @@
+use rest::prelude::*;
+
+#[test]
+fn test_event_stream_covers_every_thread() {
+    let path = std::env::temp_dir().join(format!("rest-events-{}", std::process::id())).join("events.jsonl");
+    rest::config().events_output(&path).apply();
+
+    expect!(2 + 2).to_equal(4);
+
+    let failing = std::thread::Builder::new()
+        .name("api::tests::test_total".to_string())
+        .spawn(|| {
+            expect!(5).to_be_less_than(3);
+        })
+        .unwrap();
+    expect!(failing.join().is_err()).to_be_true();
+
+    rest::Reporter::summarize();
+
+    let stream = std::fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = stream.lines().collect();
+
+    expect!(lines.len()).to_equal(4);
+    expect!(lines[0]).to_contain(r#"{"schema_version":1,"event":"success","#);
+    expect!(lines[0]).to_contain(r#""test":"test_event_stream_covers_every_thread","expression":"2 + 2","#);
+    expect!(lines[1]).to_contain(r#""event":"failure","#);
+    expect!(lines[1]).to_contain(r#""test":"api::tests::test_total","#);
+    expect!(lines[1]).to_contain(r#""location":{"file":"tests/events_test.rs","line":13,"column":13}"#);
+    expect!(lines[2]).to_contain(r#""event":"success","#);
+    expect!(lines[3]).to_contain(r#""event":"session_completed","#);
+    expect!(lines[3]).to_end_with(r#""passed":2,"failed":1}"#);
+}
@@ -15,7 +15,7 @@ static TEST_VALUE_MUTEX: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
 
 // Test state shared between tests
 thread_local! {
-    static TEST_VALUE: RefCell<u32> = RefCell::new(0);
+    static TEST_VALUE: RefCell<u32> = const { RefCell::new(0) };
 }
 
 // Reset helper for the test value - this is called by the setup function
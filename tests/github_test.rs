@@ -0,0 +1,37 @@
+use rest::prelude::*;
+use std::process::Command;
+
+// Set for the child process running the assertions, whose standard output the test reads
+const CHILD_ENV: &str = "REST_GITHUB_TEST_CHILD";
+
+#[test]
+fn test_github_annotations_through_the_reporter() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        rest::config().github_annotations(true).apply();
+
+        expect!(2 + 2).to_equal(4);
+        let failed = std::panic::catch_unwind(|| {
+            expect!(5).to_be_less_than(3);
+        });
+        expect!(failed.is_err()).to_be_true();
+
+        rest::Reporter::summarize();
+        return;
+    }
+
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "test_github_annotations_through_the_reporter", "--nocapture"])
+        .env(CHILD_ENV, "1")
+        .env_remove("GITHUB_ACTIONS")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    expect!(stdout.as_ref()).to_contain(concat!(
+        "::error file=tests/github_test.rs,line=14,col=13,title=5 is less than 3::",
+        "  ✗ is less than 3%0A      expected: be less than 3%0A      received: 5%0A  at tests/github_test.rs:14:13\n"
+    ));
+    expect!(stdout.as_ref()).to_contain("::group::Rest test summary\nTest Results:\n  1 passed / 1 failed\n");
+    expect!(stdout.as_ref()).to_contain("  1. ✗ 5 is less than 3\n");
+    expect!(stdout.as_ref()).to_contain("     at tests/github_test.rs:14:13\n::endgroup::\n");
+}
@@ -0,0 +1,27 @@
+use rest::prelude::*;
+
+#[test]
+fn test_junit_report_covers_every_thread() {
+    let path = std::env::temp_dir().join(format!("rest-junit-{}", std::process::id())).join("junit.xml");
+    rest::config().junit_output(&path).apply();
+
+    expect!(2 + 2).to_equal(4);
+
+    let failing = std::thread::Builder::new()
+        .name("api::tests::test_total".to_string())
+        .spawn(|| {
+            expect!(5).to_be_less_than(3);
+        })
+        .unwrap();
+    expect!(failing.join().is_err()).to_be_true();
+
+    rest::Reporter::summarize();
+
+    let xml = std::fs::read_to_string(&path).unwrap();
+    expect!(xml.as_str()).to_contain("<testsuite name=\"rest\" tests=\"2\" failures=\"1\" errors=\"0\" skipped=\"0\">");
+    expect!(xml.as_str())
+        .to_contain("<testcase classname=\"rest\" name=\"test_junit_report_covers_every_thread\" file=\"tests/junit_test.rs\"");
+    expect!(xml.as_str())
+        .to_contain("<testcase classname=\"api::tests\" name=\"test_total\" file=\"tests/junit_test.rs\" line=\"13\" assertions=\"1\">");
+    expect!(xml.as_str()).to_contain("<failure message=\"✗ 5 is less than 3\" type=\"AssertionFailure\">");
+}
@@ -16,7 +16,7 @@ static OUTER_TEST_MUTEX: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
 
 // Test state shared between tests
 thread_local! {
-    static TEST_VALUE: RefCell<u32> = RefCell::new(0);
+    static TEST_VALUE: RefCell<u32> = const { RefCell::new(0) };
 }
 
 // Helper to set the test value
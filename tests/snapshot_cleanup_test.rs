@@ -0,0 +1,62 @@
+use rest::prelude::*;
+use std::path::Path;
+use std::process::{Command, Output};
+
+// Set for the child process checking the snapshot, whose snapshot file the test reads
+const CHILD_ENV: &str = "REST_SNAPSHOT_CLEANUP_TEST_CHILD";
+
+const SNAPSHOT_FILE: &str = "tests/__snapshots__/snapshot_cleanup_test.rs.snap";
+
+const OBSOLETE_ENTRY: &str = "\n== snapshot_cleanup_test::renamed_test 1 ==\nold\n== end ==\n";
+
+// Run this test binary again as the child, with the extra arguments and removal enabled or not
+fn run_child(args: &[&str], remove_obsolete: bool) -> Output {
+    let mut command = Command::new(std::env::current_exe().unwrap());
+    command.args(args).env(CHILD_ENV, "1").env_remove("REST_UPDATE_SNAPSHOTS").env_remove("GITHUB_ACTIONS");
+    if remove_obsolete {
+        command.env("REST_REMOVE_OBSOLETE_SNAPSHOTS", "1");
+    } else {
+        command.env_remove("REST_REMOVE_OBSOLETE_SNAPSHOTS");
+    }
+
+    command.output().unwrap()
+}
+
+#[test]
+fn test_obsolete_snapshots_are_only_removed_after_full_runs() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        expect!(42).to_match_snapshot();
+        rest::Reporter::summarize();
+        return;
+    }
+
+    let _ = std::fs::remove_file(SNAPSHOT_FILE);
+
+    // The first run records the snapshot, then an entry of a renamed test is added
+    assert!(run_child(&[], false).status.success());
+    let recorded = std::fs::read_to_string(SNAPSHOT_FILE).unwrap();
+    std::fs::write(SNAPSHOT_FILE, format!("{}{}", recorded, OBSOLETE_ENTRY)).unwrap();
+
+    // Summarizing only reports the obsolete entry
+    let output = run_child(&[], false);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    expect!(stdout.as_ref()).not().to_contain("Removed Obsolete Snapshots");
+    expect!(std::fs::read_to_string(SNAPSHOT_FILE).unwrap()).to_contain("renamed_test 1");
+
+    // A filtered run keeps it, even with removal enabled
+    let output = run_child(&["test_obsolete_snapshots_are_only_removed_after_full_runs"], true);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    expect!(stderr.as_ref()).to_contain("rest: obsolete snapshots are kept");
+    expect!(std::fs::read_to_string(SNAPSHOT_FILE).unwrap()).to_contain("renamed_test 1");
+
+    // A full run with removal enabled removes it, and keeps the checked snapshot
+    let output = run_child(&[], true);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    expect!(stdout.as_ref()).to_contain("Removed Obsolete Snapshots:\n  tests/__snapshots__/snapshot_cleanup_test.rs.snap › ");
+    let remaining = std::fs::read_to_string(SNAPSHOT_FILE).unwrap();
+    expect!(remaining.as_str()).not().to_contain("renamed_test 1");
+    expect!(remaining).to_equal(recorded);
+
+    std::fs::remove_file(SNAPSHOT_FILE).unwrap();
+    let _ = std::fs::remove_dir(Path::new(SNAPSHOT_FILE).parent().unwrap());
+}
@@ -0,0 +1,32 @@
+use rest::prelude::*;
+
+#[test]
+#[soft]
+fn test_soft_attribute_passes() {
+    expect!(2 + 2).to_equal(4);
+    expect!("rest").to_have_length(4);
+}
+
+#[test]
+#[should_panic(expected = "2 of 3 soft assertions failed")]
+#[soft]
+fn test_soft_attribute_reports_every_failure() {
+    let code = 500;
+    let body = "error";
+
+    expect!(code).to_equal(200);
+    expect!(body).to_have_length(5);
+    expect!(body).to_contain("ok");
+}
+
+#[test]
+fn test_soft_assertions_function() {
+    let result = std::panic::catch_unwind(|| {
+        soft_assertions(|| {
+            expect!(1).to_be_greater_than(2);
+            expect!(3).to_be_less_than(2);
+        });
+    });
+
+    expect!(result.is_err()).to_be_true();
+}